@@ -4,7 +4,13 @@
 
 use criterion::*;
 
-use erfa::{aliases::eraGd2gc, time::gst06a, Ellipsoid};
+use erfa::{
+    aliases::eraGd2gc,
+    earth::{position_velocity_00, EarthEphemerisBuilder},
+    prenut::{nut00a, nut00a_fast, nut06a_with_accuracy, NutationAccuracy},
+    time::{gst06a, gst06a_with_accuracy},
+    Ellipsoid,
+};
 
 fn erfa(c: &mut Criterion) {
     let mwa_latitude_radians = -0.4660608448386394;
@@ -49,7 +55,89 @@ fn erfa(c: &mut Criterion) {
             gst06a(2451545.0, -1421.3, 2451545.0, -1421.3);
         })
     });
+
+    c.bench_function("position_velocity_00", |b| {
+        b.iter(|| {
+            position_velocity_00(2451545.0, -1421.3);
+        })
+    });
+
+    let eph = EarthEphemerisBuilder::new(2451545.0, -1500.0, 366.0)
+        .build()
+        .unwrap();
+    c.bench_function("EarthEphemeris::get", |b| {
+        b.iter(|| {
+            eph.get(2451545.0, -1421.3).unwrap();
+        })
+    });
+}
+
+fn nutation(c: &mut Criterion) {
+    let date1 = [2400000.5; 4];
+    let date2 = [53736.0, 53736.25, 53736.5, 53736.75];
+
+    c.bench_function("erfa-sys::eraNut00a x4", |b| {
+        b.iter(|| {
+            let mut dpsi = 0.0;
+            let mut deps = 0.0;
+            for i in 0..4 {
+                unsafe {
+                    erfa_sys::eraNut00a(
+                        black_box(date1[i]),
+                        black_box(date2[i]),
+                        &mut dpsi,
+                        &mut deps,
+                    );
+                }
+            }
+        })
+    });
+
+    c.bench_function("nut00a x4", |b| {
+        b.iter(|| {
+            for i in 0..4 {
+                nut00a(black_box(date1[i]), black_box(date2[i]));
+            }
+        })
+    });
+
+    c.bench_function("nut00a_fast x4", |b| {
+        b.iter(|| {
+            for i in 0..4 {
+                nut00a_fast(black_box(date1[i]), black_box(date2[i]));
+            }
+        })
+    });
+
+    #[cfg(feature = "wide")]
+    c.bench_function("simd::nut00a_x4", |b| {
+        b.iter(|| erfa::prenut::simd::nut00a_x4(black_box(date1), black_box(date2)))
+    });
+}
+
+fn accuracy(c: &mut Criterion) {
+    for accuracy in [
+        NutationAccuracy::Full,
+        NutationAccuracy::Truncated,
+        NutationAccuracy::Low,
+    ] {
+        c.bench_function(&format!("nut06a_with_accuracy {accuracy:?}"), |b| {
+            b.iter(|| nut06a_with_accuracy(black_box(2451545.0), black_box(-1421.3), accuracy))
+        });
+
+        c.bench_function(&format!("gst06a_with_accuracy {accuracy:?}"), |b| {
+            b.iter(|| {
+                gst06a_with_accuracy(
+                    black_box(2451545.0),
+                    black_box(-1421.3),
+                    black_box(2451545.0),
+                    black_box(-1421.3),
+                    accuracy,
+                )
+            })
+        });
+    }
 }
 
-criterion_group!(benches, erfa);
+criterion_group!(benches, erfa, nutation, accuracy);
 criterion_main!(benches);
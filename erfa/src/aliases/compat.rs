@@ -0,0 +1,589 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Functions with the same signatures as the ERFA C library.
+//!
+//! The functions in [`aliases`](super) have ERFA's names but Rust signatures
+//! (tuples, `Result`s and enums). The functions here follow the C API instead,
+//! so that C code using ERFA (or SOFA) can be ported line by line:
+//!
+//! * arrays are passed by reference, and results are written through `&mut`
+//!   out-parameters in the same order as the C arguments;
+//! * ellipsoids are identified by their `i32` ERFA identifiers;
+//! * functions that return a status in C return the same `i32` status here,
+//!   and leave (or set) their outputs as the C functions do on failure.
+//!
+//! Functions whose C signatures are already expressible in Rust (e.g.
+//! `eraEra00`) are re-exported unchanged. Unlike C, an input can't also be
+//! used as an output (e.g. `eraRxp(r, p, p)`); copy it first.
+//!
+//! See the corresponding functions in [`aliases`](super) for documentation.
+
+#![allow(non_snake_case)]
+
+pub use super::{
+    eraAnp, eraEpj, eraEra00, eraFad03, eraFae03, eraFaf03, eraFaju03, eraFal03, eraFalp03,
+    eraFama03, eraFame03, eraFane03, eraFaom03, eraFapa03, eraFasa03, eraFaur03, eraFave03,
+    eraGmst06, eraGst06a, eraHd2pa, eraIr, eraObl06, eraPas, eraRx, eraRy, eraRz, eraS06, eraSeps,
+    eraSp00, eraZr,
+};
+use crate::{
+    earth, ellipsoid, prenut, separation, time, transform, vectors_and_matrices as vm, Ellipsoid,
+    ErfaError,
+};
+
+/* Earth. */
+
+/// Earth position and velocity. (`eraEpv00`)
+pub fn eraEpv00(date1: f64, date2: f64, pvh: &mut [[f64; 3]; 2], pvb: &mut [[f64; 3]; 2]) -> i32 {
+    let pv = earth::position_velocity_00(date1, date2);
+    *pvh = pv.pvh;
+    *pvb = pv.pvb;
+    if pv.validity.is_nominal() {
+        0
+    } else {
+        1
+    }
+}
+
+/// Position and velocity of a terrestrial observing station. (`eraPvtob`)
+#[allow(clippy::too_many_arguments)]
+pub fn eraPvtob(
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    sp: f64,
+    theta: f64,
+    pv: &mut [[f64; 3]; 2],
+) {
+    *pv = earth::observatory_position_velocity(elong, phi, hm, xp, yp, sp, theta);
+}
+
+/// Form the matrix of polar motion, IAU 2000. (`eraPom00`)
+pub fn eraPom00(xp: f64, yp: f64, sp: f64, rpom: &mut [[f64; 3]; 3]) {
+    *rpom = earth::polar_motion_matrix_00(xp, yp, sp);
+}
+
+/* Ellipsoids and geodetic transforms. */
+
+/// Earth reference ellipsoids. (`eraEform`)
+pub fn eraEform(n: i32, a: &mut f64, f: &mut f64) -> i32 {
+    match ellipsoid::ellipsoid_form(n) {
+        Ok((ea, ef)) => {
+            *a = ea;
+            *f = ef;
+            0
+        }
+        Err(e) => {
+            *a = 0.0;
+            *f = 0.0;
+            e.to_status("eraEform")
+        }
+    }
+}
+
+/// Transform geocentric coordinates to geodetic using the specified reference
+/// ellipsoid. (`eraGc2gd`)
+pub fn eraGc2gd(n: i32, xyz: &[f64; 3], elong: &mut f64, phi: &mut f64, height: &mut f64) -> i32 {
+    let result = match Ellipsoid::try_from(n) {
+        Ok(e) => transform::geocentric_to_geodetic(e, *xyz).map_err(|_| ErfaError::Internal {
+            function: "eraGc2gd",
+        }),
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok([e, p, h]) => {
+            *elong = e;
+            *phi = p;
+            *height = h;
+            0
+        }
+        Err(e) => {
+            *elong = -1e9;
+            *phi = -1e9;
+            *height = -1e9;
+            e.to_status("eraGc2gd")
+        }
+    }
+}
+
+/// Transform geocentric coordinates to geodetic for a reference ellipsoid of
+/// specified form. (`eraGc2gde`)
+pub fn eraGc2gde(
+    a: f64,
+    f: f64,
+    xyz: &[f64; 3],
+    elong: &mut f64,
+    phi: &mut f64,
+    height: &mut f64,
+) -> i32 {
+    match transform::geocentric_to_geodetic_inner(a, f, *xyz) {
+        Ok([e, p, h]) => {
+            *elong = e;
+            *phi = p;
+            *height = h;
+            0
+        }
+        Err(e) => e.to_status("eraGc2gde"),
+    }
+}
+
+/// Transform geodetic coordinates to geocentric using the specified reference
+/// ellipsoid. (`eraGd2gc`)
+pub fn eraGd2gc(n: i32, elong: f64, phi: f64, height: f64, xyz: &mut [f64; 3]) -> i32 {
+    let result = Ellipsoid::try_from(n)
+        .and_then(|e| transform::geodetic_to_geocentric(e, elong, phi, height));
+    match result {
+        Ok(r) => {
+            *xyz = r;
+            0
+        }
+        Err(e) => {
+            *xyz = [0.0; 3];
+            e.to_status("eraGd2gc")
+        }
+    }
+}
+
+/// Transform geodetic coordinates to geocentric for a reference ellipsoid of
+/// specified form. (`eraGd2gce`)
+pub fn eraGd2gce(a: f64, f: f64, elong: f64, phi: f64, height: f64, xyz: &mut [f64; 3]) -> i32 {
+    match transform::geodetic_to_geocentric_inner(a, f, elong, phi, height) {
+        Ok(r) => {
+            *xyz = r;
+            0
+        }
+        Err(e) => e.to_status("eraGd2gce"),
+    }
+}
+
+/* Precession and nutation. */
+
+/// Extract the x,y coordinates of the Celestial Intermediate Pole from the
+/// bias-precession-nutation matrix. (`eraBpn2xy`)
+pub fn eraBpn2xy(rbpn: &[[f64; 3]; 3], x: &mut f64, y: &mut f64) {
+    (*x, *y) = prenut::bpn_to_xy(*rbpn);
+}
+
+/// Equation of the origins, given the classical NPB matrix and the quantity
+/// s. (`eraEors`)
+pub fn eraEors(rnpb: &[[f64; 3]; 3], s: f64) -> f64 {
+    prenut::eors(*rnpb, s)
+}
+
+/// Form rotation matrix given the Fukushima-Williams angles. (`eraFw2m`)
+pub fn eraFw2m(gamb: f64, phib: f64, psi: f64, eps: f64, r: &mut [[f64; 3]; 3]) {
+    *r = prenut::fw_to_matrix(gamb, phib, psi, eps);
+}
+
+/// Nutation, IAU 2000A model. (`eraNut00a`)
+pub fn eraNut00a(date1: f64, date2: f64, dpsi: &mut f64, deps: &mut f64) {
+    (*dpsi, *deps) = prenut::nut00a(date1, date2);
+}
+
+/// IAU 2000A nutation with adjustments to match the IAU 2006 precession.
+/// (`eraNut06a`)
+pub fn eraNut06a(date1: f64, date2: f64, dpsi: &mut f64, deps: &mut f64) {
+    (*dpsi, *deps) = prenut::nut06a(date1, date2);
+}
+
+/// Precession angles, IAU 2006, equinox based. (`eraP06e`)
+#[allow(clippy::too_many_arguments)]
+pub fn eraP06e(
+    date1: f64,
+    date2: f64,
+    eps0: &mut f64,
+    psia: &mut f64,
+    oma: &mut f64,
+    bpa: &mut f64,
+    bqa: &mut f64,
+    pia: &mut f64,
+    bpia: &mut f64,
+    epsa: &mut f64,
+    chia: &mut f64,
+    za: &mut f64,
+    zetaa: &mut f64,
+    thetaa: &mut f64,
+    pa: &mut f64,
+    gam: &mut f64,
+    phi: &mut f64,
+    psi: &mut f64,
+) {
+    let p = prenut::precession_angles(date1, date2);
+    *eps0 = p.eps0;
+    *psia = p.psia;
+    *oma = p.oma;
+    *bpa = p.bpa;
+    *bqa = p.bqa;
+    *pia = p.pia;
+    *bpia = p.bpia;
+    *epsa = p.epsa;
+    *chia = p.chia;
+    *za = p.za;
+    *zetaa = p.zetaa;
+    *thetaa = p.thetaa;
+    *pa = p.pa;
+    *gam = p.gam;
+    *phi = p.phi;
+    *psi = p.psi;
+}
+
+/// Precession angles, IAU 2006 (Fukushima-Williams 4-angle formulation).
+/// (`eraPfw06`)
+pub fn eraPfw06(
+    date1: f64,
+    date2: f64,
+    gamb: &mut f64,
+    phib: &mut f64,
+    psib: &mut f64,
+    epsa: &mut f64,
+) {
+    let p = prenut::precession_angles_fw06(date1, date2);
+    *gamb = p.gamb;
+    *phib = p.phib;
+    *psib = p.psib;
+    *epsa = p.epsa;
+}
+
+/// Precession matrix (including frame bias) from GCRS to a specified date,
+/// IAU 2006 model. (`eraPmat06`)
+pub fn eraPmat06(date1: f64, date2: f64, rbp: &mut [[f64; 3]; 3]) {
+    *rbp = prenut::precession_matrix_06(date1, date2);
+}
+
+/// Form the matrix of precession-nutation for a given date (including frame
+/// bias), IAU 2006 precession and IAU 2000A nutation models. (`eraPnm06a`)
+pub fn eraPnm06a(date1: f64, date2: f64, rbpn: &mut [[f64; 3]; 3]) {
+    *rbpn = prenut::pn_matrix_06a(date1, date2);
+}
+
+/* Separation and position angles. */
+
+/// Position-angle from two p-vectors. (`eraPap`)
+pub fn eraPap(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    separation::position_angle_vectors(*a, *b)
+}
+
+/// Angular separation between two p-vectors. (`eraSepp`)
+pub fn eraSepp(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    separation::sep_vectors(*a, *b)
+}
+
+/* Time. */
+
+/// Julian Epoch to Julian Date. (`eraEpj2jd`)
+pub fn eraEpj2jd(epj: f64, djm0: &mut f64, djm: &mut f64) {
+    (*djm0, *djm) = time::julian_epoch_to_date(epj);
+}
+
+/// Greenwich apparent sidereal time, IAU 2006, given the NPB matrix.
+/// (`eraGst06`)
+pub fn eraGst06(uta: f64, utb: f64, tta: f64, ttb: f64, rnpb: &[[f64; 3]; 3]) -> f64 {
+    time::gst06(uta, utb, tta, ttb, *rnpb)
+}
+
+/// Time scale transformation: TAI to TT. (`eraTaitt`)
+pub fn eraTaitt(tai1: f64, tai2: f64, tt1: &mut f64, tt2: &mut f64) -> i32 {
+    (*tt1, *tt2) = time::tai_to_tt(tai1, tai2);
+    0
+}
+
+/// Time scale transformation: TAI to UT1. (`eraTaiut1`)
+pub fn eraTaiut1(tai1: f64, tai2: f64, dta: f64, ut11: &mut f64, ut12: &mut f64) -> i32 {
+    (*ut11, *ut12) = time::tai_to_ut1(tai1, tai2, dta);
+    0
+}
+
+/// Time scale transformation: TDB to TT. (`eraTdbtt`)
+pub fn eraTdbtt(tdb1: f64, tdb2: f64, dtr: f64, tt1: &mut f64, tt2: &mut f64) -> i32 {
+    (*tt1, *tt2) = time::tdb_to_tt(tdb1, tdb2, dtr);
+    0
+}
+
+/// Time scale transformation: TT to TAI. (`eraTttai`)
+pub fn eraTttai(tt1: f64, tt2: f64, tai1: &mut f64, tai2: &mut f64) -> i32 {
+    (*tai1, *tai2) = time::tt_to_tai(tt1, tt2);
+    0
+}
+
+/// Time scale transformation: TT to TDB. (`eraTttdb`)
+pub fn eraTttdb(tt1: f64, tt2: f64, dtr: f64, tdb1: &mut f64, tdb2: &mut f64) -> i32 {
+    (*tdb1, *tdb2) = time::tt_to_tdb(tt1, tt2, dtr);
+    0
+}
+
+/// Time scale transformation: TT to UT1. (`eraTtut1`)
+pub fn eraTtut1(tt1: f64, tt2: f64, dt: f64, ut11: &mut f64, ut12: &mut f64) -> i32 {
+    (*ut11, *ut12) = time::tt_to_ut1(tt1, tt2, dt);
+    0
+}
+
+/// Time scale transformation: UT1 to TAI. (`eraUt1tai`)
+pub fn eraUt1tai(ut11: f64, ut12: f64, dta: f64, tai1: &mut f64, tai2: &mut f64) -> i32 {
+    (*tai1, *tai2) = time::ut1_to_tai(ut11, ut12, dta);
+    0
+}
+
+/// Time scale transformation: UT1 to TT. (`eraUt1tt`)
+pub fn eraUt1tt(ut11: f64, ut12: f64, dt: f64, tt1: &mut f64, tt2: &mut f64) -> i32 {
+    (*tt1, *tt2) = time::ut1_to_tt(ut11, ut12, dt);
+    0
+}
+
+/* Coordinate transforms. */
+
+/// Horizon to equatorial coordinates. (`eraAe2hd`)
+pub fn eraAe2hd(az: f64, el: f64, phi: f64, ha: &mut f64, dec: &mut f64) {
+    (*ha, *dec) = transform::azel_to_hadec(az, el, phi);
+}
+
+/// Equatorial to horizon coordinates. (`eraHd2ae`)
+pub fn eraHd2ae(ha: f64, dec: f64, phi: f64, az: &mut f64, el: &mut f64) {
+    (*az, *el) = transform::hadec_to_azel(ha, dec, phi);
+}
+
+/// P-vector to spherical coordinates. (`eraC2s`)
+pub fn eraC2s(p: &[f64; 3], theta: &mut f64, phi: &mut f64) {
+    (*theta, *phi) = transform::cartesian_to_spherical(*p);
+}
+
+/// Convert spherical coordinates to Cartesian. (`eraS2c`)
+pub fn eraS2c(theta: f64, phi: f64, c: &mut [f64; 3]) {
+    *c = transform::spherical_to_cartesian(theta, phi);
+}
+
+/// P-vector to spherical polar coordinates. (`eraP2s`)
+pub fn eraP2s(p: &[f64; 3], theta: &mut f64, phi: &mut f64, r: &mut f64) {
+    (*theta, *phi, *r) = transform::cartesian_to_spherical_polar(*p);
+}
+
+/// Spherical polar coordinates to p-vector. (`eraS2p`)
+pub fn eraS2p(theta: f64, phi: f64, r: f64, p: &mut [f64; 3]) {
+    *p = transform::spherical_polar_to_cartesian(theta, phi, r);
+}
+
+/// Convert position/velocity from Cartesian to spherical coordinates.
+/// (`eraPv2s`)
+pub fn eraPv2s(
+    pv: &[[f64; 3]; 2],
+    theta: &mut f64,
+    phi: &mut f64,
+    r: &mut f64,
+    td: &mut f64,
+    pd: &mut f64,
+    rd: &mut f64,
+) {
+    (*theta, *phi, *r, *td, *pd, *rd) = transform::pv_to_spherical_polar(*pv);
+}
+
+/// Convert position/velocity from spherical to Cartesian coordinates.
+/// (`eraS2pv`)
+pub fn eraS2pv(theta: f64, phi: f64, r: f64, td: f64, pd: f64, rd: f64, pv: &mut [[f64; 3]; 2]) {
+    *pv = transform::spherical_polar_to_pv(theta, phi, r, td, pd, rd);
+}
+
+/* Vectors and matrices. */
+
+/// Copy a p-vector. (`eraCp`)
+pub fn eraCp(p: &[f64; 3], c: &mut [f64; 3]) {
+    *c = *p;
+}
+
+/// Copy an r-matrix. (`eraCr`)
+pub fn eraCr(r: &[[f64; 3]; 3], c: &mut [[f64; 3]; 3]) {
+    *c = *r;
+}
+
+/// p-vector inner (=scalar=dot) product. (`eraPdp`)
+pub fn eraPdp(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    vm::inner_product(*a, *b)
+}
+
+/// Modulus of p-vector. (`eraPm`)
+pub fn eraPm(p: &[f64; 3]) -> f64 {
+    vm::modulus(*p)
+}
+
+/// Convert a p-vector into modulus and unit vector. (`eraPn`)
+pub fn eraPn(p: &[f64; 3], r: &mut f64, u: &mut [f64; 3]) {
+    (*r, *u) = vm::modulus_and_unit_vector(*p);
+}
+
+/// p-vector outer (=vector=cross) product. (`eraPxp`)
+pub fn eraPxp(a: &[f64; 3], b: &[f64; 3], axb: &mut [f64; 3]) {
+    *axb = vm::outer_product(*a, *b);
+}
+
+/// Outer (=vector=cross) product of two pv-vectors. (`eraPvxpv`)
+pub fn eraPvxpv(a: &[[f64; 3]; 2], b: &[[f64; 3]; 2], axb: &mut [[f64; 3]; 2]) {
+    /* a x b = position part of result. */
+    let p = vm::outer_product(a[0], b[0]);
+
+    /* a x bdot + adot x b = velocity part of result. */
+    let axbd = vm::outer_product(a[0], b[1]);
+    let adxb = vm::outer_product(a[1], b[0]);
+    *axb = [p, [axbd[0] + adxb[0], axbd[1] + adxb[1], axbd[2] + adxb[2]]];
+}
+
+/// Multiply a p-vector by a scalar. (`eraSxp`)
+pub fn eraSxp(s: f64, p: &[f64; 3], sp: &mut [f64; 3]) {
+    *sp = vm::multiply(s, *p);
+}
+
+/// Express an r-matrix as an r-vector. (`eraRm2v`)
+pub fn eraRm2v(r: &[[f64; 3]; 3], w: &mut [f64; 3]) {
+    *w = vm::matrix_to_rotation_vector(*r);
+}
+
+/// Form the r-matrix corresponding to a given r-vector. (`eraRv2m`)
+pub fn eraRv2m(w: &[f64; 3], r: &mut [[f64; 3]; 3]) {
+    *r = vm::rotation_vector_to_matrix(*w);
+}
+
+/// Multiply a p-vector by an r-matrix. (`eraRxp`)
+pub fn eraRxp(r: &[[f64; 3]; 3], p: &[f64; 3], rp: &mut [f64; 3]) {
+    *rp = vm::mat_mul_pvec(*r, *p);
+}
+
+/// Multiply a pv-vector by an r-matrix. (`eraRxpv`)
+pub fn eraRxpv(r: &[[f64; 3]; 3], pv: &[[f64; 3]; 2], rpv: &mut [[f64; 3]; 2]) {
+    *rpv = vm::mat_mul_pvvec(*r, *pv);
+}
+
+/// Multiply two r-matrices. (`eraRxr`)
+pub fn eraRxr(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3], atb: &mut [[f64; 3]; 3]) {
+    *atb = vm::multiply_matrices(*a, *b);
+}
+
+/// Transpose an r-matrix. (`eraTr`)
+pub fn eraTr(r: &[[f64; 3]; 3], rt: &mut [[f64; 3]; 3]) {
+    *rt = vm::transpose(*r);
+}
+
+/// Multiply a p-vector by the transpose of an r-matrix. (`eraTrxp`)
+pub fn eraTrxp(r: &[[f64; 3]; 3], p: &[f64; 3], trp: &mut [f64; 3]) {
+    *trp = vm::transpose_mul_pvec(*r, *p);
+}
+
+/// Multiply a pv-vector by the transpose of an r-matrix. (`eraTrxpv`)
+pub fn eraTrxpv(r: &[[f64; 3]; 3], pv: &[[f64; 3]; 2], trpv: &mut [[f64; 3]; 2]) {
+    *trpv = vm::transpose_mul_pvvec(*r, *pv);
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_eraGc2gd_compat() {
+        let mut xyz = [2e6, 3e6, 5.244e6];
+        for n in [0, 1, 2, 3, 4] {
+            let (mut e, mut p, mut h) = (0.0, 0.0, 0.0);
+            let status = eraGc2gd(n, &xyz, &mut e, &mut p, &mut h);
+            let (mut ee, mut ep, mut eh) = (0.0, 0.0, 0.0);
+            let expected =
+                unsafe { erfa_sys::eraGc2gd(n, xyz.as_mut_ptr(), &mut ee, &mut ep, &mut eh) };
+            assert_eq!(status, expected);
+            assert_abs_diff_eq!(e, ee);
+            assert_abs_diff_eq!(p, ep);
+            assert_abs_diff_eq!(h, eh, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_eraGd2gc_compat() {
+        for n in [0, 1, 2, 3, 4] {
+            let mut xyz = [1.0; 3];
+            let status = eraGd2gc(n, 3.1, -0.5, 2500.0, &mut xyz);
+            let mut expected_xyz = [1.0; 3];
+            let expected =
+                unsafe { erfa_sys::eraGd2gc(n, 3.1, -0.5, 2500.0, expected_xyz.as_mut_ptr()) };
+            assert_eq!(status, expected);
+            assert_abs_diff_eq!(xyz.as_slice(), expected_xyz.as_slice(), epsilon = 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_eraGc2gde_compat() {
+        let mut xyz = [2e6, 3e6, 5.244e6];
+        for (a, f) in [(6378136.0, 0.0033528), (6378137.0, 1.0), (0.0, 0.003)] {
+            let (mut e, mut p, mut h) = (0.0, 0.0, 0.0);
+            let status = eraGc2gde(a, f, &xyz, &mut e, &mut p, &mut h);
+            let (mut ee, mut ep, mut eh) = (0.0, 0.0, 0.0);
+            let expected =
+                unsafe { erfa_sys::eraGc2gde(a, f, xyz.as_mut_ptr(), &mut ee, &mut ep, &mut eh) };
+            assert_eq!(status, expected);
+            assert_abs_diff_eq!(e, ee);
+            assert_abs_diff_eq!(p, ep);
+            assert_abs_diff_eq!(h, eh, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_eraEform_compat() {
+        for n in [0, 1, 2, 3, 4] {
+            let (mut a, mut f) = (1.0, 1.0);
+            let status = eraEform(n, &mut a, &mut f);
+            let (mut ea, mut ef) = (1.0, 1.0);
+            let expected = unsafe { erfa_sys::eraEform(n, &mut ea, &mut ef) };
+            assert_eq!(status, expected);
+            assert_eq!((a, f), (ea, ef));
+        }
+    }
+
+    #[test]
+    fn test_eraEpv00_compat() {
+        for (date1, date2) in [(2400000.5, 53411.52501161), (2400000.5, -30000.0)] {
+            let mut pvh = [[0.0; 3]; 2];
+            let mut pvb = [[0.0; 3]; 2];
+            let status = eraEpv00(date1, date2, &mut pvh, &mut pvb);
+            let mut epvh = [[0.0; 3]; 2];
+            let mut epvb = [[0.0; 3]; 2];
+            let expected =
+                unsafe { erfa_sys::eraEpv00(date1, date2, epvh.as_mut_ptr(), epvb.as_mut_ptr()) };
+            assert_eq!(status, expected);
+            for i in 0..2 {
+                assert_abs_diff_eq!(pvh[i].as_slice(), epvh[i].as_slice());
+                assert_abs_diff_eq!(pvb[i].as_slice(), epvb[i].as_slice());
+            }
+        }
+    }
+
+    #[test]
+    fn test_eraPvxpv_compat() {
+        let mut a = [[2.0, 2.0, 3.0], [6.0, 0.0, 4.0]];
+        let mut b = [[1.0, 3.0, 4.0], [0.0, 2.0, 8.0]];
+        let mut axb = [[0.0; 3]; 2];
+        eraPvxpv(&a, &b, &mut axb);
+        let mut expected = [[0.0; 3]; 2];
+        unsafe { erfa_sys::eraPvxpv(a.as_mut_ptr(), b.as_mut_ptr(), expected.as_mut_ptr()) };
+        assert_abs_diff_eq!(axb[0].as_slice(), expected[0].as_slice());
+        assert_abs_diff_eq!(axb[1].as_slice(), expected[1].as_slice());
+        // ERFA's test values.
+        assert_eq!(axb, [[-1.0, -5.0, 4.0], [-2.0, -36.0, 22.0]]);
+    }
+
+    #[test]
+    fn test_eraP06e_compat() {
+        let mut ours = [0.0; 16];
+        let mut theirs = [0.0; 16];
+        let [a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14, a15] = &mut ours;
+        eraP06e(
+            2400000.5, 52541.0, a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14,
+            a15,
+        );
+        let [b0, b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11, b12, b13, b14, b15] = &mut theirs;
+        unsafe {
+            erfa_sys::eraP06e(
+                2400000.5, 52541.0, b0, b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11, b12, b13,
+                b14, b15,
+            )
+        };
+        assert_abs_diff_eq!(ours.as_slice(), theirs.as_slice());
+    }
+}
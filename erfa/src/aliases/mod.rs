@@ -4,15 +4,22 @@
 
 //! Function names used by the ERFA C library.
 
+pub mod compat;
 #[cfg(test)]
 mod tests;
 
 pub use crate::{
-    earth::{earth_rotation_angle_00 as eraEra00, position_velocity_00 as eraEpv00},
+    earth::{
+        earth_rotation_angle_00 as eraEra00, observatory_position_velocity as eraPvtob,
+        polar_motion_matrix_00 as eraPom00, position_velocity_00 as eraEpv00,
+        tio_locator_00 as eraSp00,
+    },
+    ellipsoid::ellipsoid_form as eraEform,
     fundamental_argument::{
         d03 as eraFad03, e03 as eraFae03, f03 as eraFaf03, ju03 as eraFaju03, l03 as eraFal03,
-        lp03 as eraFalp03, ma03 as eraFama03, me03 as eraFame03, om03 as eraFaom03,
-        pa03 as eraFapa03, sa03 as eraFasa03, ur03 as eraFaur03, ve03 as eraFave03,
+        lp03 as eraFalp03, ma03 as eraFama03, me03 as eraFame03, ne03 as eraFane03,
+        om03 as eraFaom03, pa03 as eraFapa03, sa03 as eraFasa03, ur03 as eraFaur03,
+        ve03 as eraFave03,
     },
     misc::norm_angle as eraAnp,
     prenut::{
@@ -21,22 +28,33 @@ pub use crate::{
         precession_angles as eraP06e, precession_angles_fw06 as eraPfw06,
         precession_matrix_06 as eraPmat06,
     },
-    separation::{sep_spherical_coords as eraSeps, sep_vectors as eraSepp},
+    separation::{
+        position_angle_spherical_coords as eraPas, position_angle_vectors as eraPap,
+        sep_spherical_coords as eraSeps, sep_vectors as eraSepp,
+    },
     time::{
         gmst06 as eraGmst06, gst06 as eraGst06, gst06a as eraGst06a,
-        julian_date_to_epoch as eraEpj, julian_epoch_to_date as eraEpj2jd, S06 as eraS06,
+        julian_date_to_epoch as eraEpj, julian_epoch_to_date as eraEpj2jd, tai_to_tt as eraTaitt,
+        tai_to_ut1 as eraTaiut1, tdb_to_tt as eraTdbtt, tt_to_tai as eraTttai,
+        tt_to_tdb as eraTttdb, tt_to_ut1 as eraTtut1, ut1_to_tai as eraUt1tai,
+        ut1_to_tt as eraUt1tt, S06 as eraS06,
     },
     transform::{
         azel_to_hadec as eraAe2hd, cartesian_to_spherical as eraC2s,
-        geocentric_to_geodetic as eraGc2gd, geocentric_to_geodetic_inner as eraGc2gde,
-        geodetic_to_geocentric as eraGd2gc, geodetic_to_geocentric_inner as eraGd2gce,
-        hadec_to_azel as eraHd2ae, hadec_to_parallactic_angle as eraHd2pa,
+        cartesian_to_spherical_polar as eraP2s, geocentric_to_geodetic as eraGc2gd,
+        geocentric_to_geodetic_inner as eraGc2gde, geodetic_to_geocentric as eraGd2gc,
+        geodetic_to_geocentric_inner as eraGd2gce, hadec_to_azel as eraHd2ae,
+        hadec_to_parallactic_angle as eraHd2pa, pv_to_spherical_polar as eraPv2s,
+        spherical_polar_to_cartesian as eraS2p, spherical_polar_to_pv as eraS2pv,
         spherical_to_cartesian as eraS2c,
     },
     vectors_and_matrices::{
         copy_matrix as eraCr, copy_vector as eraCp, init_matrix as eraIr, inner_product as eraPdp,
-        mat_mul_pvec as eraRxp, mat_mul_pvvec as eraRxpv, modulus as eraPm,
-        modulus_and_unit_vector as eraPn, multiply as eraSxp, multiply_matrices as eraRxr,
-        outer_product as eraPvxpv, rotate_x as eraRx, rotate_z as eraRz,
+        mat_mul_pvec as eraRxp, mat_mul_pvvec as eraRxpv, matrix_to_rotation_vector as eraRm2v,
+        modulus as eraPm, modulus_and_unit_vector as eraPn, multiply as eraSxp,
+        multiply_matrices as eraRxr, outer_product as eraPvxpv, rotate_x as eraRx,
+        rotate_y as eraRy, rotate_z as eraRz, rotation_vector_to_matrix as eraRv2m,
+        transpose as eraTr, transpose_mul_pvec as eraTrxp, transpose_mul_pvvec as eraTrxpv,
+        zero_matrix as eraZr,
     },
 };
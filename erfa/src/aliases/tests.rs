@@ -7,12 +7,15 @@
 use approx::assert_abs_diff_eq;
 
 use super::{
-    eraAe2hd, eraAnp, eraBpn2xy, eraC2s, eraCp, eraCr, eraEors, eraEpj, eraEpj2jd, eraEpv00,
-    eraEra00, eraFad03, eraFae03, eraFaf03, eraFaju03, eraFal03, eraFalp03, eraFama03, eraFame03,
-    eraFaom03, eraFapa03, eraFasa03, eraFaur03, eraFave03, eraFw2m, eraGc2gd, eraGc2gde, eraGd2gc,
-    eraGd2gce, eraGmst06, eraGst06, eraGst06a, eraHd2ae, eraHd2pa, eraIr, eraNut00a, eraNut06a,
-    eraObl06, eraP06e, eraPdp, eraPfw06, eraPm, eraPmat06, eraPn, eraPnm06a, eraPvxpv, eraRx,
-    eraRxp, eraRxpv, eraRxr, eraRz, eraS06, eraS2c, eraSepp, eraSeps, eraSxp,
+    eraAe2hd, eraAnp, eraBpn2xy, eraC2s, eraCp, eraCr, eraEform, eraEors, eraEpj, eraEpj2jd,
+    eraEpv00, eraEra00, eraFad03, eraFae03, eraFaf03, eraFaju03, eraFal03, eraFalp03, eraFama03,
+    eraFame03, eraFane03, eraFaom03, eraFapa03, eraFasa03, eraFaur03, eraFave03, eraFw2m, eraGc2gd,
+    eraGc2gde, eraGd2gc, eraGd2gce, eraGmst06, eraGst06, eraGst06a, eraHd2ae, eraHd2pa, eraIr,
+    eraNut00a, eraNut06a, eraObl06, eraP06e, eraP2s, eraPap, eraPas, eraPdp, eraPfw06, eraPm,
+    eraPmat06, eraPn, eraPnm06a, eraPom00, eraPv2s, eraPvtob, eraPvxpv, eraRm2v, eraRv2m, eraRx,
+    eraRxp, eraRxpv, eraRxr, eraRy, eraRz, eraS06, eraS2c, eraS2p, eraS2pv, eraSepp, eraSeps,
+    eraSp00, eraSxp, eraTaitt, eraTaiut1, eraTdbtt, eraTr, eraTrxp, eraTrxpv, eraTttai, eraTttdb,
+    eraTtut1, eraUt1tai, eraUt1tt, eraZr,
 };
 use crate::Ellipsoid;
 
@@ -95,6 +98,27 @@ fn test_eraC2s() {
     assert_abs_diff_eq!(result.1, expected.1);
 }
 
+#[test]
+fn test_eraEform() {
+    for n in [0, 1, 2, 3, 4] {
+        let result = eraEform(n);
+        let expected = unsafe {
+            let mut a = 0.0;
+            let mut f = 0.0;
+            let status = erfa_sys::eraEform(n, &mut a, &mut f);
+            (status, a, f)
+        };
+        match result {
+            Ok((a, f)) => {
+                assert_eq!(expected.0, 0);
+                assert_abs_diff_eq!(a, expected.1);
+                assert_abs_diff_eq!(f, expected.2);
+            }
+            Err(_) => assert_eq!(expected.0, -1),
+        }
+    }
+}
+
 #[test]
 fn test_eraEpj() {
     for (date1, date2) in [
@@ -151,11 +175,48 @@ fn test_eraEpv00() {
             let status = erfa_sys::eraEpv00(date1, date2, pvh.as_mut_ptr(), pvb.as_mut_ptr());
             (status, pvh, pvb)
         };
-        assert_eq!(result.0, expected.0 == 1);
-        assert_abs_diff_eq!(result.1[0].as_slice(), expected.1[0].as_slice());
-        assert_abs_diff_eq!(result.1[1].as_slice(), expected.1[1].as_slice());
-        assert_abs_diff_eq!(result.2[0].as_slice(), expected.2[0].as_slice());
-        assert_abs_diff_eq!(result.2[1].as_slice(), expected.2[1].as_slice());
+        assert_eq!(!result.validity.is_nominal(), expected.0 == 1);
+        assert_abs_diff_eq!(result.pvh[0].as_slice(), expected.1[0].as_slice());
+        assert_abs_diff_eq!(result.pvh[1].as_slice(), expected.1[1].as_slice());
+        assert_abs_diff_eq!(result.pvb[0].as_slice(), expected.2[0].as_slice());
+        assert_abs_diff_eq!(result.pvb[1].as_slice(), expected.2[1].as_slice());
+    }
+}
+
+#[test]
+fn test_eraEpv00_validity() {
+    use crate::{
+        constants::{ERFA_DJ00, ERFA_DJY},
+        earth::{position_velocity_00_strict, DateValidity},
+        ErfaError,
+    };
+
+    for (years, validity) in [
+        (0.0, DateValidity::Nominal),
+        (-100.0, DateValidity::Nominal),
+        (150.0, DateValidity::Degraded2),
+        (-400.0, DateValidity::Degraded10),
+        (900.0, DateValidity::Degraded60),
+        (-1500.0, DateValidity::Unreliable),
+    ] {
+        let (date1, date2) = (ERFA_DJ00, years * ERFA_DJY);
+        let result = eraEpv00(date1, date2);
+        let status = unsafe {
+            let mut pvh = [[0.0; 3]; 2];
+            let mut pvb = [[0.0; 3]; 2];
+            erfa_sys::eraEpv00(date1, date2, pvh.as_mut_ptr(), pvb.as_mut_ptr())
+        };
+        assert_eq!(result.validity, validity);
+        assert_eq!(!result.validity.is_nominal(), status == 1);
+
+        match position_velocity_00_strict(date1, date2) {
+            Ok(pv) => assert_eq!(pv, result),
+            Err(ErfaError::DateOutOfRange { validity: v, .. }) => {
+                assert!(!validity.is_nominal());
+                assert_eq!(v, validity);
+            }
+            Err(e) => panic!("unexpected error: {e}"),
+        }
     }
 }
 
@@ -246,6 +307,15 @@ fn test_eraFame03() {
     }
 }
 
+#[test]
+fn test_eraFane03() {
+    for t in [0.1, 1.2, 12.34] {
+        let result = eraFane03(t);
+        let expected = unsafe { erfa_sys::eraFane03(t) };
+        assert_abs_diff_eq!(result, expected);
+    }
+}
+
 #[test]
 fn test_eraFaom03() {
     for t in [0.1, 1.2, 12.34] {
@@ -312,7 +382,7 @@ fn test_eraFw2m() {
 fn test_eraGc2gd() {
     let mut xyz = [0.1, 0.2, 0.3];
     for e in [Ellipsoid::WGS84, Ellipsoid::GRS80, Ellipsoid::WGS72] {
-        let result = eraGc2gd(e, xyz);
+        let result = eraGc2gd(e, xyz).unwrap();
         let mut elong = 0.0;
         let mut phi = 0.0;
         let mut height = 0.0;
@@ -354,6 +424,30 @@ fn test_eraGc2gde() {
     }
 }
 
+#[test]
+fn test_eraGc2gde_errors() {
+    let mut xyz = [2e6, 3e6, 5.244e6];
+    for (a, f) in [
+        (6378137.0, 1.0),
+        (6378137.0, -0.1),
+        (0.0, 0.003),
+        (-1.0, 1.0),
+    ] {
+        let result = eraGc2gde(a, f, xyz);
+        let status = unsafe {
+            let mut elong = 0.0;
+            let mut phi = 0.0;
+            let mut height = 0.0;
+            erfa_sys::eraGc2gde(a, f, xyz.as_mut_ptr(), &mut elong, &mut phi, &mut height)
+        };
+        match (result, status) {
+            (Err(crate::ErfaError::InvalidFlattening { .. }), -1) => (),
+            (Err(crate::ErfaError::InvalidEquatorialRadius { .. }), -2) => (),
+            (r, s) => panic!("unexpected result {r:?} for ERFA status {s}"),
+        }
+    }
+}
+
 #[test]
 fn test_eraGd2gc() {
     let (elong, phi, height) = (0.1, 0.2, 0.3);
@@ -435,6 +529,91 @@ fn test_eraGst06a() {
     }
 }
 
+#[test]
+fn test_eraGst06a_with_accuracy() {
+    use crate::{
+        constants::ERFA_DMAS2R,
+        prenut::{self, NutationAccuracy},
+        time,
+    };
+
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        /* The full model is unchanged. */
+        let full = NutationAccuracy::Full;
+        assert_eq!(
+            prenut::nut06a_with_accuracy(date1, date2, full),
+            prenut::nut06a(date1, date2)
+        );
+        assert_eq!(
+            prenut::pn_matrix_06a_with_accuracy(date1, date2, full),
+            prenut::pn_matrix_06a(date1, date2)
+        );
+        assert_eq!(
+            time::gst06a_with_accuracy(date1, date2, date1, date2, full),
+            time::gst06a(date1, date2, date1, date2)
+        );
+
+        /* The truncated series is IAU 2000B, bar the fundamental arguments */
+        /* and the P03 adjustments. */
+        let (dpsi, deps) = prenut::nut06a_with_accuracy(date1, date2, NutationAccuracy::Truncated);
+        let mut dpsi_b = 0.0;
+        let mut deps_b = 0.0;
+        unsafe { erfa_sys::eraNut00b(date1, date2, &mut dpsi_b, &mut deps_b) };
+        assert_abs_diff_eq!(dpsi, dpsi_b, epsilon = 0.05 * ERFA_DMAS2R);
+        assert_abs_diff_eq!(deps, deps_b, epsilon = 0.05 * ERFA_DMAS2R);
+
+        /* The documented errors. */
+        let gst = unsafe { erfa_sys::eraGst06a(date1, date2, date1, date2) };
+        let mut dpsi_a = 0.0;
+        let mut deps_a = 0.0;
+        unsafe { erfa_sys::eraNut06a(date1, date2, &mut dpsi_a, &mut deps_a) };
+        for (accuracy, dpsi_max, deps_max, gst_max) in [
+            (NutationAccuracy::Truncated, 3.1, 1.2, 2.8),
+            (NutationAccuracy::Low, 0.9, 0.6, 0.8),
+        ] {
+            let (dpsi, deps) = prenut::nut06a_with_accuracy(date1, date2, accuracy);
+            assert_abs_diff_eq!(dpsi, dpsi_a, epsilon = dpsi_max * ERFA_DMAS2R);
+            assert_abs_diff_eq!(deps, deps_a, epsilon = deps_max * ERFA_DMAS2R);
+            let result = time::gst06a_with_accuracy(date1, date2, date1, date2, accuracy);
+            assert_abs_diff_eq!(result, gst, epsilon = gst_max * ERFA_DMAS2R);
+        }
+    }
+}
+
+#[test]
+fn test_eraGst06a_with_args() {
+    use crate::{fundamental_argument::FundamentalArguments, prenut, time};
+
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        let fa = FundamentalArguments::new(date1, date2);
+        let result = time::gst06a_with_args(date1, date2, &fa);
+        let expected = unsafe { erfa_sys::eraGst06a(date1, date2, date1, date2) };
+        assert_abs_diff_eq!(result, expected);
+        assert_eq!(result, time::gst06a(date1, date2, date1, date2));
+
+        let rnpb = prenut::pn_matrix_06a_with_args(&fa);
+        assert_eq!(rnpb, prenut::pn_matrix_06a(date1, date2));
+        assert_eq!(prenut::nut00a_with_args(&fa), prenut::nut00a(date1, date2));
+        let (x, y) = prenut::bpn_to_xy(rnpb);
+        assert_eq!(
+            time::S06_with_args(&fa, x, y),
+            time::S06(date1, date2, x, y)
+        );
+    }
+}
+
 #[test]
 fn test_eraHd2ae() {
     let ha = 0.123;
@@ -494,6 +673,28 @@ fn test_eraNut00a() {
     }
 }
 
+#[test]
+fn test_eraNut00a_fast() {
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+        (2400000.5, -300000.0),
+        (2400000.5, 400000.0),
+    ] {
+        let result = crate::prenut::nut00a_fast(date1, date2);
+        let expected = unsafe {
+            let mut expected = (0.0, 0.0);
+            erfa_sys::eraNut00a(date1, date2, &mut expected.0, &mut expected.1);
+            expected
+        };
+        assert_abs_diff_eq!(result.0, expected.0, epsilon = 1e-19);
+        assert_abs_diff_eq!(result.1, expected.1, epsilon = 1e-19);
+    }
+}
+
 #[test]
 fn test_eraNut06a() {
     for (date1, date2) in [
@@ -565,22 +766,63 @@ fn test_eraP06e() {
             );
             expected
         };
+        assert_abs_diff_eq!(result.eps0, expected.0);
+        assert_abs_diff_eq!(result.psia, expected.1);
+        assert_abs_diff_eq!(result.oma, expected.2);
+        assert_abs_diff_eq!(result.bpa, expected.3);
+        assert_abs_diff_eq!(result.bqa, expected.4);
+        assert_abs_diff_eq!(result.pia, expected.5);
+        assert_abs_diff_eq!(result.bpia, expected.6);
+        assert_abs_diff_eq!(result.epsa, expected.7);
+        assert_abs_diff_eq!(result.chia, expected.8);
+        assert_abs_diff_eq!(result.za, expected.9);
+        assert_abs_diff_eq!(result.zetaa, expected.10);
+        assert_abs_diff_eq!(result.thetaa, expected.11);
+        assert_abs_diff_eq!(result.pa, expected.12);
+        assert_abs_diff_eq!(result.gam, expected.13);
+        assert_abs_diff_eq!(result.phi, expected.14);
+        assert_abs_diff_eq!(result.psi, expected.15);
+    }
+}
+
+#[test]
+fn test_eraP2s() {
+    for mut p in [[100.0, -50.0, 25.0], [0.0, 0.0, 0.0]] {
+        let result = eraP2s(p);
+        let expected = unsafe {
+            let mut expected = (0.0, 0.0, 0.0);
+            erfa_sys::eraP2s(
+                p.as_mut_ptr(),
+                &mut expected.0,
+                &mut expected.1,
+                &mut expected.2,
+            );
+            expected
+        };
         assert_abs_diff_eq!(result.0, expected.0);
         assert_abs_diff_eq!(result.1, expected.1);
         assert_abs_diff_eq!(result.2, expected.2);
-        assert_abs_diff_eq!(result.3, expected.3);
-        assert_abs_diff_eq!(result.4, expected.4);
-        assert_abs_diff_eq!(result.5, expected.5);
-        assert_abs_diff_eq!(result.6, expected.6);
-        assert_abs_diff_eq!(result.7, expected.7);
-        assert_abs_diff_eq!(result.8, expected.8);
-        assert_abs_diff_eq!(result.9, expected.9);
-        assert_abs_diff_eq!(result.10, expected.10);
-        assert_abs_diff_eq!(result.11, expected.11);
-        assert_abs_diff_eq!(result.12, expected.12);
-        assert_abs_diff_eq!(result.13, expected.13);
-        assert_abs_diff_eq!(result.14, expected.14);
-        assert_abs_diff_eq!(result.15, expected.15);
+    }
+}
+
+#[test]
+fn test_eraPap() {
+    let mut a = [1.0, 0.1, 0.2];
+    for mut b in [[-3.0, 1e-3, 0.2], [0.0; 3]] {
+        let result = eraPap(a, b);
+        let expected = unsafe { erfa_sys::eraPap(a.as_mut_ptr(), b.as_mut_ptr()) };
+        assert_abs_diff_eq!(result, expected);
+    }
+}
+
+#[test]
+fn test_eraPas() {
+    let al = 1.0;
+    let ap = 0.1;
+    for (bl, bp) in [(0.2, -1.0), (1.0, 0.1)] {
+        let result = eraPas(al, ap, bl, bp);
+        let expected = unsafe { erfa_sys::eraPas(al, ap, bl, bp) };
+        assert_abs_diff_eq!(result, expected);
     }
 }
 
@@ -615,10 +857,10 @@ fn test_eraPfw06() {
             );
             expected
         };
-        assert_abs_diff_eq!(result.0, expected.0);
-        assert_abs_diff_eq!(result.1, expected.1);
-        assert_abs_diff_eq!(result.2, expected.2);
-        assert_abs_diff_eq!(result.3, expected.3);
+        assert_abs_diff_eq!(result.gamb, expected.0);
+        assert_abs_diff_eq!(result.phib, expected.1);
+        assert_abs_diff_eq!(result.psib, expected.2);
+        assert_abs_diff_eq!(result.epsa, expected.3);
     }
 }
 
@@ -685,6 +927,82 @@ fn test_eraPnm06a() {
     }
 }
 
+#[test]
+fn test_eraPv2s() {
+    for mut pv in [
+        [
+            [
+                -0.4514964673880165,
+                0.03093394277342585,
+                0.05594668105108779,
+            ],
+            [
+                1.29227085066326e-5,
+                2.652814182060692e-6,
+                2.568431853930293e-6,
+            ],
+        ],
+        [[0.0, 0.0, 0.0], [1.0, -2.0, 3.0]],
+        [[0.0, 0.0, 1.0], [1.0, -2.0, 3.0]],
+    ] {
+        let result = eraPv2s(pv);
+        let expected = unsafe {
+            let mut expected = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+            erfa_sys::eraPv2s(
+                pv.as_mut_ptr(),
+                &mut expected.0,
+                &mut expected.1,
+                &mut expected.2,
+                &mut expected.3,
+                &mut expected.4,
+                &mut expected.5,
+            );
+            expected
+        };
+        assert_abs_diff_eq!(result.0, expected.0);
+        assert_abs_diff_eq!(result.1, expected.1);
+        assert_abs_diff_eq!(result.2, expected.2);
+        assert_abs_diff_eq!(result.3, expected.3);
+        assert_abs_diff_eq!(result.4, expected.4);
+        assert_abs_diff_eq!(result.5, expected.5);
+    }
+}
+
+#[test]
+fn test_eraPom00() {
+    let xp = 2.55060238e-7;
+    let yp = 1.860359247e-6;
+    let sp = -0.13671745807288915e-10;
+    let result = eraPom00(xp, yp, sp);
+    let expected = unsafe {
+        let mut rpom = [[0.0; 3]; 3];
+        erfa_sys::eraPom00(xp, yp, sp, rpom.as_mut_ptr());
+        rpom
+    };
+    assert_abs_diff_eq!(result[0].as_slice(), expected[0].as_slice());
+    assert_abs_diff_eq!(result[1].as_slice(), expected[1].as_slice());
+    assert_abs_diff_eq!(result[2].as_slice(), expected[2].as_slice());
+}
+
+#[test]
+fn test_eraPvtob() {
+    let elong = 2.0;
+    let phi = 0.5;
+    let hm = 3000.0;
+    let xp = 1e-6;
+    let yp = -0.5e-6;
+    let sp = 1e-8;
+    let theta = 5.0;
+    let result = eraPvtob(elong, phi, hm, xp, yp, sp, theta);
+    let expected = unsafe {
+        let mut pv = [[0.0; 3]; 2];
+        erfa_sys::eraPvtob(elong, phi, hm, xp, yp, sp, theta, pv.as_mut_ptr());
+        pv
+    };
+    assert_abs_diff_eq!(result[0].as_slice(), expected[0].as_slice());
+    assert_abs_diff_eq!(result[1].as_slice(), expected[1].as_slice());
+}
+
 #[test]
 fn test_eraPvxpv() {
     let mut a = [1.0, 2.0, 3.0];
@@ -698,6 +1016,37 @@ fn test_eraPvxpv() {
     assert_abs_diff_eq!(result.as_slice(), expected.as_slice());
 }
 
+#[test]
+fn test_eraRm2v() {
+    let mut r = [
+        [0.00, -0.80, -0.60],
+        [0.80, -0.36, 0.48],
+        [0.60, 0.48, -0.64],
+    ];
+    let result = eraRm2v(r);
+    let expected = unsafe {
+        let mut w = [0.0; 3];
+        erfa_sys::eraRm2v(r.as_mut_ptr(), w.as_mut_ptr());
+        w
+    };
+    assert_abs_diff_eq!(result.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn test_eraRv2m() {
+    for mut w in [[0.0, 1.41371669, -1.88495559], [0.0; 3]] {
+        let result = eraRv2m(w);
+        let expected = unsafe {
+            let mut r = [[0.0; 3]; 3];
+            erfa_sys::eraRv2m(w.as_mut_ptr(), r.as_mut_ptr());
+            r
+        };
+        assert_abs_diff_eq!(result[0].as_slice(), expected[0].as_slice());
+        assert_abs_diff_eq!(result[1].as_slice(), expected[1].as_slice());
+        assert_abs_diff_eq!(result[2].as_slice(), expected[2].as_slice());
+    }
+}
+
 #[test]
 fn test_eraRx() {
     for phi in [0.12, 4.5, 123.69] {
@@ -755,6 +1104,21 @@ fn test_eraRxpv() {
     assert_abs_diff_eq!(result[1].as_slice(), expected[1].as_slice());
 }
 
+#[test]
+fn test_eraRy() {
+    for theta in [0.12, 4.5, 123.69] {
+        let mut r = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 11.0]];
+        let mut r2 = r;
+        eraRy(theta, &mut r);
+        unsafe {
+            erfa_sys::eraRy(theta, r2.as_mut_ptr());
+        };
+        assert_abs_diff_eq!(r[0].as_slice(), r2[0].as_slice());
+        assert_abs_diff_eq!(r[1].as_slice(), r2[1].as_slice());
+        assert_abs_diff_eq!(r[2].as_slice(), r2[2].as_slice());
+    }
+}
+
 #[test]
 fn test_eraRz() {
     for phi in [0.12, 4.5, 123.69] {
@@ -800,6 +1164,34 @@ fn test_eraS2c() {
     assert_abs_diff_eq!(result.as_slice(), expected.as_slice());
 }
 
+#[test]
+fn test_eraS2p() {
+    let theta = -3.21;
+    let phi = 0.123;
+    let r = 0.456;
+    let result = eraS2p(theta, phi, r);
+    let expected = unsafe {
+        let mut expected = [0.0; 3];
+        erfa_sys::eraS2p(theta, phi, r, expected.as_mut_ptr());
+        expected
+    };
+    assert_abs_diff_eq!(result.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn test_eraS2pv() {
+    let (theta, phi, r) = (-3.21, 0.123, 0.456);
+    let (td, pd, rd) = (-7.8e-6, 9.01e-6, -1.23e-5);
+    let result = eraS2pv(theta, phi, r, td, pd, rd);
+    let expected = unsafe {
+        let mut pv = [[0.0; 3]; 2];
+        erfa_sys::eraS2pv(theta, phi, r, td, pd, rd, pv.as_mut_ptr());
+        pv
+    };
+    assert_abs_diff_eq!(result[0].as_slice(), expected[0].as_slice());
+    assert_abs_diff_eq!(result[1].as_slice(), expected[1].as_slice());
+}
+
 #[test]
 fn test_eraSepp() {
     let mut a = [0.0, 2.0, 1.0];
@@ -820,6 +1212,21 @@ fn test_eraSeps() {
     assert_abs_diff_eq!(result, expected);
 }
 
+#[test]
+fn test_eraSp00() {
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        let result = eraSp00(date1, date2);
+        let expected = unsafe { erfa_sys::eraSp00(date1, date2) };
+        assert_abs_diff_eq!(result, expected);
+    }
+}
+
 #[test]
 fn test_eraSxp() {
     let s = 0.4;
@@ -832,3 +1239,233 @@ fn test_eraSxp() {
     };
     assert_abs_diff_eq!(result.as_slice(), expected.as_slice());
 }
+
+#[test]
+fn test_eraTaitt() {
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        let result = eraTaitt(date1, date2);
+        let expected = unsafe {
+            let mut d1 = 0.0;
+            let mut d2 = 0.0;
+            let status = erfa_sys::eraTaitt(date1, date2, &mut d1, &mut d2);
+            assert_eq!(status, 0);
+            (d1, d2)
+        };
+        assert_abs_diff_eq!(result.0, expected.0);
+        assert_abs_diff_eq!(result.1, expected.1);
+    }
+}
+
+#[test]
+fn test_eraTaiut1() {
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        let result = eraTaiut1(date1, date2, -0.6);
+        let expected = unsafe {
+            let mut d1 = 0.0;
+            let mut d2 = 0.0;
+            let status = erfa_sys::eraTaiut1(date1, date2, -0.6, &mut d1, &mut d2);
+            assert_eq!(status, 0);
+            (d1, d2)
+        };
+        assert_abs_diff_eq!(result.0, expected.0);
+        assert_abs_diff_eq!(result.1, expected.1);
+    }
+}
+
+#[test]
+fn test_eraTdbtt() {
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        let result = eraTdbtt(date1, date2, -0.000201);
+        let expected = unsafe {
+            let mut d1 = 0.0;
+            let mut d2 = 0.0;
+            let status = erfa_sys::eraTdbtt(date1, date2, -0.000201, &mut d1, &mut d2);
+            assert_eq!(status, 0);
+            (d1, d2)
+        };
+        assert_abs_diff_eq!(result.0, expected.0);
+        assert_abs_diff_eq!(result.1, expected.1);
+    }
+}
+
+#[test]
+fn test_eraTr() {
+    let mut r = [[2.0, 3.0, 2.0], [3.0, 2.0, 3.0], [3.0, 4.0, 5.0]];
+    let result = eraTr(r);
+    let expected = unsafe {
+        let mut rt = [[0.0; 3]; 3];
+        erfa_sys::eraTr(r.as_mut_ptr(), rt.as_mut_ptr());
+        rt
+    };
+    assert_abs_diff_eq!(result[0].as_slice(), expected[0].as_slice());
+    assert_abs_diff_eq!(result[1].as_slice(), expected[1].as_slice());
+    assert_abs_diff_eq!(result[2].as_slice(), expected[2].as_slice());
+}
+
+#[test]
+fn test_eraTrxp() {
+    let mut r = [[2.0, 3.0, 2.0], [3.0, 2.0, 3.0], [3.0, 4.0, 5.0]];
+    let mut p = [0.2, 1.5, 0.1];
+    let result = eraTrxp(r, p);
+    let expected = unsafe {
+        let mut trp = [0.0; 3];
+        erfa_sys::eraTrxp(r.as_mut_ptr(), p.as_mut_ptr(), trp.as_mut_ptr());
+        trp
+    };
+    assert_abs_diff_eq!(result.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn test_eraTrxpv() {
+    let mut r = [[2.0, 3.0, 2.0], [3.0, 2.0, 3.0], [3.0, 4.0, 5.0]];
+    let mut pv = [[0.2, 1.5, 0.1], [1.5, 0.2, 0.1]];
+    let result = eraTrxpv(r, pv);
+    let expected = unsafe {
+        let mut trpv = [[0.0; 3]; 2];
+        erfa_sys::eraTrxpv(r.as_mut_ptr(), pv.as_mut_ptr(), trpv.as_mut_ptr());
+        trpv
+    };
+    assert_abs_diff_eq!(result[0].as_slice(), expected[0].as_slice());
+    assert_abs_diff_eq!(result[1].as_slice(), expected[1].as_slice());
+}
+
+#[test]
+fn test_eraTttai() {
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        let result = eraTttai(date1, date2);
+        let expected = unsafe {
+            let mut d1 = 0.0;
+            let mut d2 = 0.0;
+            let status = erfa_sys::eraTttai(date1, date2, &mut d1, &mut d2);
+            assert_eq!(status, 0);
+            (d1, d2)
+        };
+        assert_abs_diff_eq!(result.0, expected.0);
+        assert_abs_diff_eq!(result.1, expected.1);
+    }
+}
+
+#[test]
+fn test_eraTttdb() {
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        let result = eraTttdb(date1, date2, -0.000201);
+        let expected = unsafe {
+            let mut d1 = 0.0;
+            let mut d2 = 0.0;
+            let status = erfa_sys::eraTttdb(date1, date2, -0.000201, &mut d1, &mut d2);
+            assert_eq!(status, 0);
+            (d1, d2)
+        };
+        assert_abs_diff_eq!(result.0, expected.0);
+        assert_abs_diff_eq!(result.1, expected.1);
+    }
+}
+
+#[test]
+fn test_eraTtut1() {
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        let result = eraTtut1(date1, date2, 64.8499);
+        let expected = unsafe {
+            let mut d1 = 0.0;
+            let mut d2 = 0.0;
+            let status = erfa_sys::eraTtut1(date1, date2, 64.8499, &mut d1, &mut d2);
+            assert_eq!(status, 0);
+            (d1, d2)
+        };
+        assert_abs_diff_eq!(result.0, expected.0);
+        assert_abs_diff_eq!(result.1, expected.1);
+    }
+}
+
+#[test]
+fn test_eraUt1tai() {
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        let result = eraUt1tai(date1, date2, -0.6);
+        let expected = unsafe {
+            let mut d1 = 0.0;
+            let mut d2 = 0.0;
+            let status = erfa_sys::eraUt1tai(date1, date2, -0.6, &mut d1, &mut d2);
+            assert_eq!(status, 0);
+            (d1, d2)
+        };
+        assert_abs_diff_eq!(result.0, expected.0);
+        assert_abs_diff_eq!(result.1, expected.1);
+    }
+}
+
+#[test]
+fn test_eraUt1tt() {
+    for (date1, date2) in [
+        (2450123.7, 0.0),
+        (2451545.0, -1421.3),
+        (2400000.5, 50123.2),
+        (2450123.5, 0.2),
+        (2450143.5, -0.2),
+    ] {
+        let result = eraUt1tt(date1, date2, 64.8499);
+        let expected = unsafe {
+            let mut d1 = 0.0;
+            let mut d2 = 0.0;
+            let status = erfa_sys::eraUt1tt(date1, date2, 64.8499, &mut d1, &mut d2);
+            assert_eq!(status, 0);
+            (d1, d2)
+        };
+        assert_abs_diff_eq!(result.0, expected.0);
+        assert_abs_diff_eq!(result.1, expected.1);
+    }
+}
+
+#[test]
+fn test_eraZr() {
+    let mut r = [[2.0, 3.0, 2.0], [3.0, 2.0, 3.0], [3.0, 4.0, 5.0]];
+    let mut r2 = r;
+    eraZr(&mut r);
+    unsafe {
+        erfa_sys::eraZr(r2.as_mut_ptr());
+    }
+    assert_abs_diff_eq!(r[0].as_slice(), r2[0].as_slice());
+    assert_abs_diff_eq!(r[1].as_slice(), r2[1].as_slice());
+    assert_abs_diff_eq!(r[2].as_slice(), r2[2].as_slice());
+}
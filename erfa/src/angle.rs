@@ -0,0 +1,443 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Typed angles.
+//!
+//! All ERFA functions take and return angles as bare `f64` radians. The types
+//! here carry the unit with the value, so that degrees, hours and radians can't
+//! be accidentally mixed. Typed variants of some functions are available in
+//! [`transform::typed`](crate::transform::typed).
+
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::{constants::*, math, ErfaError};
+
+/// An angle. Internally, this is always stored in radians.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Angle(f64);
+
+impl Angle {
+    /// A zero angle.
+    pub const ZERO: Angle = Angle(0.0);
+
+    /// Create an [`Angle`] from radians.
+    pub const fn from_radians(rad: f64) -> Angle {
+        Angle(rad)
+    }
+
+    /// Create an [`Angle`] from degrees.
+    pub fn from_degrees(deg: f64) -> Angle {
+        Angle(deg * ERFA_DD2R)
+    }
+
+    /// Create an [`Angle`] from arcseconds.
+    pub fn from_arcseconds(arcsec: f64) -> Angle {
+        Angle(arcsec * ERFA_DAS2R)
+    }
+
+    /// Create an [`Angle`] from milliarcseconds.
+    pub fn from_milliarcseconds(mas: f64) -> Angle {
+        Angle(mas * ERFA_DMAS2R)
+    }
+
+    /// Create an [`Angle`] from hours (e.g. of right ascension).
+    pub fn from_hours(hours: f64) -> Angle {
+        Angle(hours * 3600.0 * ERFA_DS2R)
+    }
+
+    /// Create an [`Angle`] from seconds of time.
+    pub fn from_time_seconds(sec: f64) -> Angle {
+        Angle(sec * ERFA_DS2R)
+    }
+
+    /// Convert degrees, arcminutes, arcseconds to an [`Angle`]. (`eraAf2a`)
+    ///
+    /// Given:
+    /// * `negative`: whether the angle is negative
+    /// * `ideg`: degrees
+    /// * `iamin`: arcminutes
+    /// * `asec`: arcseconds
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of the fields are out of
+    /// range; valid values are 0 <= `ideg` <= 359, 0 <= `iamin` <= 59 and 0 <=
+    /// `asec` < 60.
+    ///
+    /// # Notes:
+    ///
+    /// 1) Unlike `eraAf2a`, which computes a result even if the fields are out
+    ///    of range, no result is produced when a range check fails.
+    ///
+    /// 2) The sign of the result comes only from `negative`; negative fields
+    ///    are out of range.
+    ///
+    pub fn from_dms(negative: bool, ideg: i32, iamin: i32, asec: f64) -> Result<Angle, ErfaError> {
+        if !(0..=359).contains(&ideg) {
+            return Err(ErfaError::InvalidValue {
+                function: "Angle::from_dms",
+                value: "ideg",
+            });
+        }
+        if !(0..=59).contains(&iamin) {
+            return Err(ErfaError::InvalidValue {
+                function: "Angle::from_dms",
+                value: "iamin",
+            });
+        }
+        if !(0.0..60.0).contains(&asec) {
+            return Err(ErfaError::InvalidValue {
+                function: "Angle::from_dms",
+                value: "asec",
+            });
+        }
+
+        /* Compute the interval. */
+        let sign = if negative { -1.0 } else { 1.0 };
+        Ok(Angle(
+            sign * (60.0 * (60.0 * f64::from(ideg) + f64::from(iamin)) + asec) * ERFA_DAS2R,
+        ))
+    }
+
+    /// Convert hours, minutes, seconds to an [`Angle`]. (`eraTf2a`)
+    ///
+    /// Given:
+    /// * `negative`: whether the angle is negative
+    /// * `ihour`: hours
+    /// * `imin`: minutes
+    /// * `sec`: seconds
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of the fields are out of
+    /// range; valid values are 0 <= `ihour` <= 23, 0 <= `imin` <= 59 and 0 <=
+    /// `sec` < 60.
+    ///
+    /// # Notes:
+    ///
+    /// 1) Unlike `eraTf2a`, which computes a result even if the fields are out
+    ///    of range, no result is produced when a range check fails.
+    ///
+    /// 2) The sign of the result comes only from `negative`; negative fields
+    ///    are out of range.
+    ///
+    pub fn from_hms(negative: bool, ihour: i32, imin: i32, sec: f64) -> Result<Angle, ErfaError> {
+        if !(0..=23).contains(&ihour) {
+            return Err(ErfaError::InvalidValue {
+                function: "Angle::from_hms",
+                value: "ihour",
+            });
+        }
+        if !(0..=59).contains(&imin) {
+            return Err(ErfaError::InvalidValue {
+                function: "Angle::from_hms",
+                value: "imin",
+            });
+        }
+        if !(0.0..60.0).contains(&sec) {
+            return Err(ErfaError::InvalidValue {
+                function: "Angle::from_hms",
+                value: "sec",
+            });
+        }
+
+        /* Compute the interval. */
+        let sign = if negative { -1.0 } else { 1.0 };
+        Ok(Angle(
+            sign * (60.0 * (60.0 * f64::from(ihour) + f64::from(imin)) + sec) * ERFA_DS2R,
+        ))
+    }
+
+    /// Get the angle in radians.
+    pub const fn radians(self) -> f64 {
+        self.0
+    }
+
+    /// Get the angle in degrees.
+    pub fn degrees(self) -> f64 {
+        self.0 * ERFA_DR2D
+    }
+
+    /// Get the angle in arcseconds.
+    pub fn arcseconds(self) -> f64 {
+        self.0 * ERFA_DR2AS
+    }
+
+    /// Get the angle in hours.
+    pub fn hours(self) -> f64 {
+        self.0 / (3600.0 * ERFA_DS2R)
+    }
+
+    /// Normalize the angle into the range `0 <= a < 2pi`. (`eraAnp`)
+    pub fn normalized(self) -> Angle {
+        Angle(crate::misc::norm_angle(self.0))
+    }
+
+    /// Simultaneously compute the sine and cosine of the angle.
+    pub fn sin_cos(self) -> (f64, f64) {
+        math::sin_cos(self.0)
+    }
+
+    /// Compute the sine of the angle.
+    pub fn sin(self) -> f64 {
+        math::sin(self.0)
+    }
+
+    /// Compute the cosine of the angle.
+    pub fn cos(self) -> f64 {
+        math::cos(self.0)
+    }
+
+    /// Compute the absolute value of the angle.
+    pub fn abs(self) -> Angle {
+        Angle(self.0.abs())
+    }
+}
+
+impl Add for Angle {
+    type Output = Angle;
+
+    fn add(self, rhs: Angle) -> Angle {
+        Angle(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Angle {
+    type Output = Angle;
+
+    fn sub(self, rhs: Angle) -> Angle {
+        Angle(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign for Angle {
+    fn add_assign(&mut self, rhs: Angle) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Angle {
+    fn sub_assign(&mut self, rhs: Angle) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Angle {
+    type Output = Angle;
+
+    fn neg(self) -> Angle {
+        Angle(-self.0)
+    }
+}
+
+impl Mul<f64> for Angle {
+    type Output = Angle;
+
+    fn mul(self, rhs: f64) -> Angle {
+        Angle(self.0 * rhs)
+    }
+}
+
+impl Mul<Angle> for f64 {
+    type Output = Angle;
+
+    fn mul(self, rhs: Angle) -> Angle {
+        Angle(self * rhs.0)
+    }
+}
+
+impl Div<f64> for Angle {
+    type Output = Angle;
+
+    fn div(self, rhs: f64) -> Angle {
+        Angle(self.0 / rhs)
+    }
+}
+
+impl Div for Angle {
+    type Output = f64;
+
+    fn div(self, rhs: Angle) -> f64 {
+        self.0 / rhs.0
+    }
+}
+
+/// A latitude (e.g. geodetic latitude of a site, or declination). This is an
+/// [`Angle`] that is guaranteed to be in the range `-pi/2 <= phi <= pi/2`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Latitude(Angle);
+
+impl Latitude {
+    /// Create a new [`Latitude`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the angle is not in the range
+    /// `-pi/2 <= phi <= pi/2`.
+    pub fn new(phi: Angle) -> Result<Latitude, ErfaError> {
+        if !(-ERFA_DPI / 2.0..=ERFA_DPI / 2.0).contains(&phi.0) {
+            return Err(ErfaError::InvalidValue {
+                function: "Latitude::new",
+                value: "phi",
+            });
+        }
+
+        Ok(Latitude(phi))
+    }
+
+    /// Create a new [`Latitude`] without checking its range. This is used for
+    /// the results of functions that always produce valid latitudes (for valid
+    /// inputs).
+    pub(crate) const fn new_unchecked(phi: Angle) -> Latitude {
+        Latitude(phi)
+    }
+
+    /// Create a new [`Latitude`] from radians. See [`Latitude::new`].
+    pub fn from_radians(rad: f64) -> Result<Latitude, ErfaError> {
+        Latitude::new(Angle::from_radians(rad))
+    }
+
+    /// Create a new [`Latitude`] from degrees. See [`Latitude::new`].
+    pub fn from_degrees(deg: f64) -> Result<Latitude, ErfaError> {
+        Latitude::new(Angle::from_degrees(deg))
+    }
+
+    /// Get the underlying [`Angle`].
+    pub const fn angle(self) -> Angle {
+        self.0
+    }
+
+    /// Get the latitude in radians.
+    pub const fn radians(self) -> f64 {
+        self.0.radians()
+    }
+
+    /// Get the latitude in degrees.
+    pub fn degrees(self) -> f64 {
+        self.0.degrees()
+    }
+}
+
+impl From<Latitude> for Angle {
+    fn from(l: Latitude) -> Angle {
+        l.0
+    }
+}
+
+impl TryFrom<Angle> for Latitude {
+    type Error = ErfaError;
+
+    fn try_from(a: Angle) -> Result<Latitude, ErfaError> {
+        Latitude::new(a)
+    }
+}
+
+/// An hour angle. This is an [`Angle`] which is conventionally expressed in
+/// hours; the wrapper exists so that it can't be confused with other angles
+/// (e.g. azimuth) in function arguments.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct HourAngle(Angle);
+
+impl HourAngle {
+    /// Create a new [`HourAngle`].
+    pub const fn new(ha: Angle) -> HourAngle {
+        HourAngle(ha)
+    }
+
+    /// Create a new [`HourAngle`] from radians.
+    pub const fn from_radians(rad: f64) -> HourAngle {
+        HourAngle(Angle::from_radians(rad))
+    }
+
+    /// Create a new [`HourAngle`] from hours.
+    pub fn from_hours(hours: f64) -> HourAngle {
+        HourAngle(Angle::from_hours(hours))
+    }
+
+    /// Get the underlying [`Angle`].
+    pub const fn angle(self) -> Angle {
+        self.0
+    }
+
+    /// Get the hour angle in radians.
+    pub const fn radians(self) -> f64 {
+        self.0.radians()
+    }
+
+    /// Get the hour angle in hours.
+    pub fn hours(self) -> f64 {
+        self.0.hours()
+    }
+}
+
+impl From<HourAngle> for Angle {
+    fn from(ha: HourAngle) -> Angle {
+        ha.0
+    }
+}
+
+impl From<Angle> for HourAngle {
+    fn from(a: Angle) -> HourAngle {
+        HourAngle(a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_abs_diff_eq!(Angle::from_degrees(180.0).radians(), ERFA_DPI);
+        assert_abs_diff_eq!(Angle::from_hours(12.0).radians(), ERFA_DPI);
+        assert_abs_diff_eq!(Angle::from_arcseconds(3600.0).degrees(), 1.0);
+        assert_abs_diff_eq!(Angle::from_radians(ERFA_DPI).hours(), 12.0);
+        assert_abs_diff_eq!(Angle::from_degrees(-90.0).normalized().degrees(), 270.0);
+        assert_abs_diff_eq!(
+            (Angle::from_degrees(10.0) + Angle::from_degrees(20.0) * 2.0 - Angle::ZERO).degrees(),
+            50.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_from_dms_matches_erfa() {
+        let (negative, ideg, iamin, asec) = (true, 45, 13, 27.2);
+        let result = Angle::from_dms(negative, ideg, iamin, asec).unwrap();
+        let expected = unsafe {
+            let mut rad = 0.0;
+            let status = erfa_sys::eraAf2a(b'-' as _, ideg, iamin, asec, &mut rad);
+            assert_eq!(status, 0);
+            rad
+        };
+        assert_abs_diff_eq!(result.radians(), expected);
+        assert!(Angle::from_dms(false, 360, 0, 0.0).is_err());
+        assert!(Angle::from_dms(false, 0, 60, 0.0).is_err());
+        assert!(Angle::from_dms(false, 0, 0, 60.0).is_err());
+    }
+
+    #[test]
+    fn test_from_hms_matches_erfa() {
+        let (negative, ihour, imin, sec) = (false, 4, 58, 20.2);
+        let result = Angle::from_hms(negative, ihour, imin, sec).unwrap();
+        let expected = unsafe {
+            let mut rad = 0.0;
+            let status = erfa_sys::eraTf2a(b'+' as _, ihour, imin, sec, &mut rad);
+            assert_eq!(status, 0);
+            rad
+        };
+        assert_abs_diff_eq!(result.radians(), expected);
+        assert!(Angle::from_hms(false, 24, 0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_latitude_range() {
+        assert!(Latitude::from_degrees(90.0).is_ok());
+        assert!(Latitude::from_degrees(-90.0).is_ok());
+        assert!(Latitude::from_degrees(90.1).is_err());
+        assert!(Latitude::from_radians(f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Chebyshev series, for the interpolating caches.
+
+use alloc::vec::Vec;
+
+use crate::{constants::ERFA_DPI, math};
+
+/// The `n` Chebyshev nodes (of the first kind) on [-1, 1], in increasing
+/// order.
+pub(crate) fn nodes(n: usize) -> impl Iterator<Item = f64> {
+    (0..n).map(move |k| -math::cos(ERFA_DPI * (k as f64 + 0.5) / n as f64))
+}
+
+/// The coefficients of the Chebyshev series of degree `values.len() - 1` that
+/// interpolates `values`, which are given at the [`nodes`].
+pub(crate) fn fit(values: &[f64]) -> Vec<f64> {
+    let n = values.len();
+    (0..n)
+        .map(|j| {
+            let sum: f64 = values
+                .iter()
+                .enumerate()
+                .map(|(k, v)| {
+                    /* The nodes are in increasing order, i.e. reversed. */
+                    let theta = ERFA_DPI * ((n - 1 - k) as f64 + 0.5) / n as f64;
+                    v * math::cos(j as f64 * theta)
+                })
+                .sum();
+            let norm = if j == 0 { 1.0 } else { 2.0 };
+            norm * sum / n as f64
+        })
+        .collect()
+}
+
+/// Evaluate the Chebyshev series with coefficients `c` at `x` (in [-1, 1]),
+/// with Clenshaw's recurrence.
+pub(crate) fn eval(c: &[f64], x: f64) -> f64 {
+    let mut b1 = 0.0;
+    let mut b2 = 0.0;
+    for &c in c.iter().skip(1).rev() {
+        (b1, b2) = (2.0 * x * b1 - b2 + c, b1);
+    }
+    x * b1 - b2 + c[0]
+}
+
+/// Locate the offset `dt` in an interval split into `n_segments` segments of
+/// `segment_days` each: the segment index, and the position within that
+/// segment scaled to [-1, 1].
+pub(crate) fn locate(dt: f64, segment_days: f64, n_segments: usize) -> (usize, f64) {
+    let i = ((dt / segment_days) as usize).min(n_segments - 1);
+    let x = (2.0 * (dt / segment_days - i as f64) - 1.0).clamp(-1.0, 1.0);
+    (i, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_fit_reproduces_polynomials() {
+        let p = |x: f64| 0.5 - 1.5 * x + 0.25 * x * x * x;
+        let values: Vec<_> = nodes(6).map(p).collect();
+        let c = fit(&values);
+        for x in [-1.0, -0.3, 0.0, 0.77, 1.0] {
+            assert_relative_eq!(eval(&c, x), p(x), max_relative = 8.0 * f64::EPSILON);
+        }
+    }
+}
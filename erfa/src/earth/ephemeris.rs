@@ -0,0 +1,358 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A Chebyshev-fitted Earth ephemeris over a range of TDB dates.
+
+use alloc::{vec, vec::Vec};
+
+use super::position_velocity_00::{position_velocity_00, DateValidity, EarthPv};
+use crate::{
+    chebyshev,
+    constants::{ERFA_DJ00, ERFA_DJY},
+    math, ErfaError,
+};
+
+/// The number of fitted components: `pvh` and `pvb`, position and velocity.
+const N_COMPONENTS: usize = 12;
+
+/// The number of steps each gap between the fitted nodes is divided into when
+/// measuring the deviation of the fit.
+const CHECK_STEPS: usize = 4;
+
+/// The most segments an ephemeris may have: with 32 coefficients, about 300
+/// MB of coefficients.
+const MAX_SEGMENTS: usize = 100_000;
+
+/// An estimate of the largest difference between an [`EarthEphemeris`] and
+/// [`position_velocity_00`], made when the ephemeris was built.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EphemerisDeviation {
+    /// Largest position difference found, heliocentric or barycentric (AU)
+    pub position: f64,
+    /// Largest velocity difference found, heliocentric or barycentric (AU/day)
+    pub velocity: f64,
+}
+
+/// Builds an [`EarthEphemeris`].
+///
+/// With the defaults (4-day segments of 14 coefficients), the deviation from
+/// [`position_velocity_00`] over 1900-2100 is at most about 3e-13 AU (5 cm)
+/// in position and 5e-15 AU/day in velocity, as found by fitting a year at a
+/// time and comparing every 7 minutes. It is least near J2000.0 (about 1e-14
+/// AU and 2e-16 AU/day) and grows away from it with the rounding errors of
+/// [`position_velocity_00`]. All of this is far below the accuracy of
+/// [`position_velocity_00`] itself (Note 4 of that function). The deviation
+/// of a particular fit is estimated by
+/// [`EarthEphemeris::estimated_deviation`].
+///
+/// ```
+/// use erfa::earth::EarthEphemerisBuilder;
+///
+/// // A year from 2024-01-01 TDB.
+/// let eph = EarthEphemerisBuilder::new(2460310.5, 0.0, 366.0)
+///     .build()
+///     .unwrap();
+/// let pv = eph.get(2460310.5, 100.25).unwrap();
+/// assert!(eph.estimated_deviation().position < 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct EarthEphemerisBuilder {
+    date1: f64,
+    date2: f64,
+    days: f64,
+    segment_days: f64,
+    n_coeffs: usize,
+}
+
+impl EarthEphemerisBuilder {
+    /// Start building an ephemeris covering the TDB interval from
+    /// `date1+date2` to `date1+date2+days`.
+    ///
+    /// Given:
+    ///  * `date1`,`date2`: start of the interval, TDB as a 2-part Julian Date
+    ///  * `days`: length of the interval (days, > 0)
+    pub fn new(date1: f64, date2: f64, days: f64) -> Self {
+        Self {
+            date1,
+            date2,
+            days,
+            segment_days: 4.0,
+            n_coeffs: 14,
+        }
+    }
+
+    /// Set the longest interval (days) covered by one set of Chebyshev
+    /// coefficients. The default is 4. The interval may be split into at most
+    /// 100,000 segments.
+    pub fn segment_days(mut self, segment_days: f64) -> Self {
+        self.segment_days = segment_days;
+        self
+    }
+
+    /// Set the number of Chebyshev coefficients per component and segment
+    /// (2 to 32). The default is 14.
+    pub fn n_coeffs(mut self, n_coeffs: usize) -> Self {
+        self.n_coeffs = n_coeffs;
+        self
+    }
+
+    /// Fit the ephemeris, and estimate its deviation from
+    /// [`position_velocity_00`] at points between the fitted nodes.
+    ///
+    /// The cost is about `4 * n_coeffs` calls to [`position_velocity_00`] per
+    /// segment.
+    pub fn build(self) -> Result<EarthEphemeris, ErfaError> {
+        let invalid = |value| ErfaError::InvalidValue {
+            function: "EarthEphemerisBuilder::build",
+            value,
+        };
+        if !(self.days.is_finite() && self.days > 0.0 && (self.date1 + self.date2).is_finite()) {
+            return Err(invalid("days"));
+        }
+        if !(self.segment_days.is_finite()
+            && self.segment_days > 0.0
+            && self.days / self.segment_days <= MAX_SEGMENTS as f64)
+        {
+            return Err(invalid("segment_days"));
+        }
+        if !(2..=32).contains(&self.n_coeffs) {
+            return Err(invalid("n_coeffs"));
+        }
+
+        let n = self.n_coeffs;
+        let n_segments = math::ceil(self.days / self.segment_days).max(1.0) as usize;
+        let segment_days = self.days / n_segments as f64;
+        let components = |pv: &EarthPv| -> [f64; N_COMPONENTS] {
+            let [[a, b, c], [d, e, f]] = pv.pvh;
+            let [[g, h, i], [j, k, l]] = pv.pvb;
+            [a, b, c, d, e, f, g, h, i, j, k, l]
+        };
+
+        /* Fit each segment at the Chebyshev nodes. */
+        let mut coeffs = Vec::with_capacity(n_segments * N_COMPONENTS * n);
+        let nodes: Vec<f64> = chebyshev::nodes(n).collect();
+        for i in 0..n_segments {
+            let mut values = vec![[0.0; N_COMPONENTS]; n];
+            for (v, node) in values.iter_mut().zip(&nodes) {
+                let offset = (i as f64 + 0.5 * (node + 1.0)) * segment_days;
+                *v = components(&position_velocity_00(self.date1, self.date2 + offset));
+            }
+            for j in 0..N_COMPONENTS {
+                let v: Vec<f64> = values.iter().map(|v| v[j]).collect();
+                coeffs.extend(chebyshev::fit(&v));
+            }
+        }
+
+        let mut eph = EarthEphemeris {
+            date1: self.date1,
+            date2: self.date2,
+            days: self.days,
+            segment_days,
+            n_segments,
+            n_coeffs: n,
+            coeffs,
+            estimated_deviation: EphemerisDeviation {
+                position: 0.0,
+                velocity: 0.0,
+            },
+        };
+
+        /* Measure the deviation at CHECK_STEPS - 1 points between each pair */
+        /* of nodes, and between the ends and the outermost nodes. */
+        let mut bounds = vec![-1.0];
+        bounds.extend(&nodes);
+        bounds.push(1.0);
+        let mut check = vec![-1.0, 1.0];
+        for w in bounds.windows(2) {
+            check.extend(
+                (1..CHECK_STEPS).map(|k| w[0] + (w[1] - w[0]) * k as f64 / CHECK_STEPS as f64),
+            );
+        }
+        let mut dev = eph.estimated_deviation;
+        for i in 0..n_segments {
+            for x in &check {
+                let offset = (i as f64 + 0.5 * (x + 1.0)) * segment_days;
+                let direct = components(&position_velocity_00(self.date1, self.date2 + offset));
+                let fitted = eph.components(i, *x);
+                for j in 0..N_COMPONENTS {
+                    let d = (fitted[j] - direct[j]).abs();
+                    if j % 6 < 3 {
+                        dev.position = dev.position.max(d);
+                    } else {
+                        dev.velocity = dev.velocity.max(d);
+                    }
+                }
+            }
+        }
+        eph.estimated_deviation = dev;
+
+        Ok(eph)
+    }
+}
+
+/// Earth position and velocity, heliocentric and barycentric, from Chebyshev
+/// polynomials fitted to [`position_velocity_00`] over an interval of TDB.
+/// Made with an [`EarthEphemerisBuilder`].
+///
+/// Each query costs a few dozen multiply-adds per component, rather than the
+/// thousands of harmonic terms of [`position_velocity_00`].
+///
+/// # Notes:
+///
+/// 1) The components of the position and velocity are fitted independently,
+///    so the velocity is not exactly the derivative of the fitted position;
+///    both deviate from [`position_velocity_00`] by about
+///    [`EarthEphemeris::estimated_deviation`].
+///
+/// 2) The deviation is estimated when the ephemeris is built, from the
+///    largest difference found at several points between each pair of
+///    fitted nodes of every segment, where interpolation errors are largest.
+///    For a good fit, the differences are mostly the rounding errors of
+///    [`position_velocity_00`] (which grow away from J2000.0) carried into
+///    the fit, so at other times they can exceed the estimate by some tens
+///    of percent. For a coarse fit, the estimate is within about a percent
+///    of the true maximum.
+///
+/// 3) The deviation describes the fit only; the accuracy with respect to the
+///    true Earth motion is that of [`position_velocity_00`] (Note 4 of that
+///    function).
+///
+#[derive(Clone, Debug)]
+pub struct EarthEphemeris {
+    /// Start of the interval, TDB as a 2-part Julian Date.
+    date1: f64,
+    date2: f64,
+    /// Length of the interval (days).
+    days: f64,
+    /// Length of each segment (days).
+    segment_days: f64,
+    n_segments: usize,
+    /// Chebyshev coefficients per component.
+    n_coeffs: usize,
+    /// Coefficients, by segment, then component.
+    coeffs: Vec<f64>,
+    estimated_deviation: EphemerisDeviation,
+}
+
+impl EarthEphemeris {
+    /// The start of the fitted interval, TDB as a 2-part Julian Date.
+    pub fn start(&self) -> (f64, f64) {
+        (self.date1, self.date2)
+    }
+
+    /// The length of the fitted interval (days).
+    pub fn days(&self) -> f64 {
+        self.days
+    }
+
+    /// The estimated largest difference from [`position_velocity_00`], made
+    /// when the ephemeris was built (see Note 2 of [`EarthEphemeris`]).
+    pub fn estimated_deviation(&self) -> EphemerisDeviation {
+        self.estimated_deviation
+    }
+
+    /// Earth position and velocity at TDB `date1+date2`, which must lie within
+    /// the fitted interval. See [`position_velocity_00`].
+    ///
+    /// Given:
+    ///  * `date1`,`date2`: TDB as a 2-part Julian Date
+    ///
+    /// Returned (as an [`EarthPv`]):
+    ///  * `validity`: as for [`position_velocity_00`]
+    ///  * `pvh`: heliocentric Earth position/velocity (AU, AU/day)
+    ///  * `pvb`: barycentric Earth position/velocity (AU, AU/day)
+    ///
+    /// An [`ErfaError::InvalidDate`] is returned if the date is outside the
+    /// fitted interval.
+    pub fn get(&self, date1: f64, date2: f64) -> Result<EarthPv, ErfaError> {
+        /* Offset from the start of the interval, keeping precision. */
+        let dt = (date1 - self.date1) + (date2 - self.date2);
+        if !(0.0..=self.days).contains(&dt) {
+            return Err(ErfaError::InvalidDate {
+                function: "EarthEphemeris::get",
+            });
+        }
+
+        let (seg, x) = chebyshev::locate(dt, self.segment_days, self.n_segments);
+        let [a, b, c, d, e, f, g, h, i, j, k, l] = self.components(seg, x);
+        Ok(EarthPv {
+            validity: DateValidity::from_years(((date1 - ERFA_DJ00) + date2) / ERFA_DJY),
+            pvh: [[a, b, c], [d, e, f]],
+            pvb: [[g, h, i], [j, k, l]],
+        })
+    }
+
+    /// Evaluate all of the components in segment `i` at `x` (in [-1, 1]).
+    fn components(&self, i: usize, x: f64) -> [f64; N_COMPONENTS] {
+        let n = self.n_coeffs;
+        let segment = &self.coeffs[i * N_COMPONENTS * n..(i + 1) * N_COMPONENTS * n];
+        core::array::from_fn(|j| chebyshev::eval(&segment[j * n..(j + 1) * n], x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_earth_ephemeris_matches_epv00() {
+        let eph = EarthEphemerisBuilder::new(2451545.0, -1421.3, 60.0)
+            .build()
+            .unwrap();
+        let dev = eph.estimated_deviation();
+        assert!(dev.position < 1e-12, "{dev:?}");
+        assert!(dev.velocity < 1e-14, "{dev:?}");
+
+        for i in 0..=1000 {
+            let date2 = -1421.3 + i as f64 * 0.06;
+            let fitted = eph.get(2451545.0, date2).unwrap();
+            let direct = position_velocity_00(2451545.0, date2);
+            assert_eq!(fitted.validity, direct.validity);
+            for (f, d) in [(fitted.pvh, direct.pvh), (fitted.pvb, direct.pvb)] {
+                for k in 0..3 {
+                    assert!((f[0][k] - d[0][k]).abs() <= dev.position);
+                    assert!((f[1][k] - d[1][k]).abs() <= dev.velocity);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_earth_ephemeris_coarse_fit_reports_deviation() {
+        /* A deliberately poor fit must say so. */
+        let eph = EarthEphemerisBuilder::new(2400000.5, 53736.0, 40.0)
+            .segment_days(20.0)
+            .n_coeffs(4)
+            .build()
+            .unwrap();
+        let dev = eph.estimated_deviation();
+        assert!(dev.position > 1e-8, "{dev:?}");
+        let fitted = eph.get(2400000.5, 53740.123).unwrap();
+        let direct = position_velocity_00(2400000.5, 53740.123);
+        assert!((fitted.pvh[0][0] - direct.pvh[0][0]).abs() <= 2.0 * dev.position);
+    }
+
+    #[test]
+    fn test_earth_ephemeris_bounds() {
+        let build = |days, segment_days, n_coeffs| {
+            EarthEphemerisBuilder::new(2451545.0, 0.0, days)
+                .segment_days(segment_days)
+                .n_coeffs(n_coeffs)
+                .build()
+        };
+        assert!(build(0.0, 4.0, 14).is_err());
+        assert!(build(10.0, 0.0, 14).is_err());
+        assert!(build(10.0, 1e-300, 14).is_err());
+        assert!(build(1e6, 1.0, 14).is_err());
+        assert!(build(10.0, 4.0, 1).is_err());
+        assert!(build(10.0, 4.0, 33).is_err());
+
+        let eph = build(10.0, 4.0, 14).unwrap();
+        assert_eq!(eph.start(), (2451545.0, 0.0));
+        assert_eq!(eph.days(), 10.0);
+        assert!(eph.get(2451545.0, 10.0).is_ok());
+        assert!(eph.get(2451545.0, -1e-6).is_err());
+        assert!(eph.get(2451555.0, 1e-6).is_err());
+    }
+}
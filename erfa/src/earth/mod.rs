@@ -2,12 +2,21 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "alloc")]
+mod ephemeris;
 mod position_velocity_00;
-pub use position_velocity_00::position_velocity_00;
+pub mod typed;
+#[cfg(feature = "alloc")]
+pub use ephemeris::{EarthEphemeris, EarthEphemerisBuilder, EphemerisDeviation};
+pub use position_velocity_00::{
+    position_velocity_00, position_velocity_00_strict, DateValidity, EarthPv,
+};
 
 use crate::{
-    constants::{ERFA_D2PI, ERFA_DJ00},
+    constants::{ERFA_D2PI, ERFA_DAS2R, ERFA_DAYSEC, ERFA_DJ00, ERFA_DJC},
+    math,
     misc::norm_angle,
+    real::Real,
 };
 
 /// Earth rotation angle (IAU 2000 model). (`eraEra00`)
@@ -53,7 +62,7 @@ use crate::{
 /// * McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003), IERS Technical
 ///   Note No. 32, BKG (2004)
 ///
-pub fn earth_rotation_angle_00(dj1: f64, dj2: f64) -> f64 {
+pub fn earth_rotation_angle_00<R: Real>(dj1: R, dj2: R) -> R {
     /* Days since fundamental epoch. */
     let (d1, d2) = if dj1 < dj2 { (dj1, dj2) } else { (dj2, dj1) };
     let t = d1 + (d2 - ERFA_DJ00);
@@ -62,5 +71,183 @@ pub fn earth_rotation_angle_00(dj1: f64, dj2: f64) -> f64 {
     let f = d1 % 1.0 + d2 % 1.0;
 
     /* Earth rotation angle at this UT1. */
-    norm_angle(ERFA_D2PI * (f + 0.7790572732640 + 0.00273781191135448 * t))
+    norm_angle((f + 0.7790572732640 + t * 0.00273781191135448) * ERFA_D2PI)
+}
+
+/// The TIO locator s', positioning the Terrestrial Intermediate Origin on the
+/// equator of the Celestial Intermediate Pole. (`eraSp00`)
+///
+/// Given:
+///  * `date1`,`date2`: TT as a 2-part Julian Date (Note 1)
+///
+/// Returned:
+///  * the TIO locator s' in radians (Note 2)
+///
+/// # Notes:
+///
+/// 1) The TT date `date1+date2` is a Julian Date, apportioned in any convenient
+///    way between the two arguments.  For example, `JD(TT)=2450123.7` could be
+///    expressed in any of these ways, among others:
+///
+///    | `date1`   | `date2` |                    |
+///    |-----------|---------|--------------------|
+///    | 2450123.7 |     0.0 | JD method          |
+///    | 2451545.0 | -1421.3 | J2000 method       |
+///    | 2400000.5 | 50123.2 | MJD method         |
+///    | 2450123.5 |     0.2 | date & time method |
+///
+///    The JD method is the most natural and convenient to use in cases where
+///    the loss of several decimal digits of resolution is acceptable.  The
+///    J2000 method is best matched to the way the argument is handled
+///    internally and will deliver the optimum resolution.  The MJD method and
+///    the date & time methods are both good compromises between resolution and
+///    convenience.
+///
+/// 2) The TIO locator s' is obtained from polar motion observations by
+///    numerical integration, and so is in essence unpredictable.  However, it
+///    is dominated by a secular drift of about 47 microarcseconds per century,
+///    which is the approximation evaluated by the present function.
+///
+/// # Reference:
+///
+/// * McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003), IERS Technical
+///   Note No. 32, BKG (2004)
+///
+pub fn tio_locator_00(date1: f64, date2: f64) -> f64 {
+    /* Interval between fundamental epoch J2000.0 and current date (JC). */
+    let t = ((date1 - ERFA_DJ00) + date2) / ERFA_DJC;
+
+    /* Approximate s'. */
+    -47e-6 * t * ERFA_DAS2R
+}
+
+/// Form the matrix of polar motion for a given date, IAU 2000. (`eraPom00`)
+///
+/// Given:
+///  * `xp`,`yp`: coordinates of the pole (radians, Note 1)
+///  * `sp`: the TIO locator s' (radians, Note 2)
+///
+/// Returned:
+///  * `rpom`: polar-motion matrix (Note 3)
+///
+/// # Notes:
+///
+/// 1) The arguments `xp` and `yp` are the coordinates (in radians) of the
+///    Celestial Intermediate Pole with respect to the International Terrestrial
+///    Reference System (see IERS Conventions 2003), measured along the
+///    meridians 0 and 90 deg west respectively.
+///
+/// 2) The argument `sp` is the TIO locator s', in radians, which positions the
+///    Terrestrial Intermediate Origin on the equator.  It is obtained from
+///    polar motion observations by numerical integration, and so is in essence
+///    unpredictable.  However, it is dominated by a secular drift of about 47
+///    microarcseconds per century, and so can be taken into account by using
+///    `s' = -47*t`, where `t` is centuries since J2000.0.  The function
+///    [`eraSp00`](tio_locator_00) implements this approximation.
+///
+/// 3) The matrix operates in the sense `V(TRS) = rpom * V(CIP)`, meaning that
+///    it is the final rotation when computing the pointing direction to a
+///    celestial source.
+///
+/// 4) The matrix is correct for the IAU 2000 precession-nutation model.
+///
+/// # Reference:
+///
+/// * McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003), IERS Technical
+///   Note No. 32, BKG (2004)
+///
+pub fn polar_motion_matrix_00(xp: f64, yp: f64, sp: f64) -> [[f64; 3]; 3] {
+    use crate::vectors_and_matrices::{init_matrix, rotate_x, rotate_y, rotate_z};
+
+    let mut rpom = [[0.0; 3]; 3];
+
+    /* Construct the matrix. */
+    init_matrix(&mut rpom);
+    rotate_z(sp, &mut rpom);
+    rotate_y(-xp, &mut rpom);
+    rotate_x(-yp, &mut rpom);
+
+    rpom
+}
+
+/// Position and velocity of a terrestrial observing station. (`eraPvtob`)
+///
+/// Given:
+///  * `elong`: longitude (radians, east +ve, Note 1)
+///  * `phi`: latitude (geodetic, radians, Note 1)
+///  * `hm`: height above ref. ellipsoid (geodetic, m)
+///  * `xp`,`yp`: coordinates of the pole (radians, Note 2)
+///  * `sp`: the TIO locator s' (radians, Note 2)
+///  * `theta`: Earth rotation angle (radians, Note 3)
+///
+/// Returned:
+///  * `pv`: position/velocity vector (m, m/s, CIRS)
+///
+/// # Notes:
+///
+/// 1) The terrestrial coordinates are with respect to the
+///    [`WGS84`](crate::Ellipsoid::WGS84) reference ellipsoid.
+///
+/// 2) `xp` and `yp` are the coordinates (in radians) of the Celestial
+///    Intermediate Pole with respect to the International Terrestrial Reference
+///    System (see IERS Conventions), measured along the meridians 0 and 90 deg
+///    west respectively.  `sp` is the TIO locator s', in radians, which
+///    positions the Terrestrial Intermediate Origin on the equator.  For many
+///    applications, `xp`, `yp` and (especially) `sp` can be set to zero.
+///
+/// 3) If `theta` is Greenwich apparent sidereal time instead of Earth rotation
+///    angle, the result is with respect to the true equator and equinox of
+///    date, i.e. with the x-axis at the equinox rather than the celestial
+///    intermediate origin.
+///
+/// 4) The velocity units are meters per UT1 second, not per SI second.  This
+///    is unlikely to have any practical consequences in the modern era.
+///
+/// 5) No validation is performed on the arguments.  Error cases that could
+///    lead to arithmetic exceptions are trapped by the
+///    [`eraGd2gc`](crate::transform::geodetic_to_geocentric) function, and the
+///    result set to zeros.
+///
+/// # References:
+///
+/// * McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003), IERS Technical
+///   Note No. 32, BKG (2004)
+///
+/// * Urban, S. & Seidelmann, P. K. (eds), Explanatory Supplement to the
+///   Astronomical Almanac, 3rd ed., University Science Books (2013), Section
+///   7.4.3.3.
+///
+pub fn observatory_position_velocity(
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    sp: f64,
+    theta: f64,
+) -> [[f64; 3]; 2] {
+    use crate::{
+        transform::geodetic_to_geocentric, vectors_and_matrices::transpose_mul_pvec, Ellipsoid,
+    };
+
+    /* Earth rotation rate in radians per UT1 second */
+    #[allow(clippy::excessive_precision)]
+    const OM: f64 = 1.00273781191135448 * ERFA_D2PI / ERFA_DAYSEC;
+
+    /* Geodetic to geocentric transformation (WGS84). */
+    let xyzm = geodetic_to_geocentric(Ellipsoid::WGS84, elong, phi, hm).unwrap_or([0.0; 3]);
+
+    /* Polar motion and TIO position. */
+    let rpm = polar_motion_matrix_00(xp, yp, sp);
+    let [x, y, z] = transpose_mul_pvec(rpm, xyzm);
+
+    /* Functions of ERA. */
+    let (s, c) = math::sin_cos(theta);
+
+    [
+        /* Position. */
+        [c * x - s * y, s * x + c * y, z],
+        /* Velocity. */
+        [OM * (-s * x - c * y), OM * (c * x - s * y), 0.0],
+    ]
 }
@@ -2,7 +2,71 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::constants::{ERFA_DJ00, ERFA_DJY};
+use crate::{
+    constants::{ERFA_DJ00, ERFA_DJY},
+    series::{accumulate, Accumulator, PoissonTerm, Sequential},
+    ErfaError,
+};
+
+/// How far the accuracy of [`position_velocity_00`] has degraded for a given
+/// date, relative to its accuracy over 1900-2100 AD (see Note 4 of that
+/// function). The factors are for position; the velocity accuracy falls off
+/// at about half that rate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DateValidity {
+    /// 1900-2100 AD: the tabulated accuracy applies.
+    Nominal,
+    /// 1800-2200 AD: errors up to about double the nominal size.
+    Degraded2,
+    /// 1500-2500 AD: errors up to about 10 times the nominal size.
+    Degraded10,
+    /// 1000-3000 AD: errors up to about 60 times the nominal size.
+    Degraded60,
+    /// Outside 1000-3000 AD: the accuracy is unknown.
+    Unreliable,
+}
+
+impl DateValidity {
+    /// Classify a date given as Julian years since J2000.0.
+    pub(super) fn from_years(t: f64) -> Self {
+        match t.abs() {
+            t if t <= 100.0 => DateValidity::Nominal,
+            t if t <= 200.0 => DateValidity::Degraded2,
+            t if t <= 500.0 => DateValidity::Degraded10,
+            t if t <= 1000.0 => DateValidity::Degraded60,
+            _ => DateValidity::Unreliable,
+        }
+    }
+
+    /// Is the date within 1900-2100 AD? This is the opposite of ERFA's
+    /// warning status.
+    pub fn is_nominal(self) -> bool {
+        self == DateValidity::Nominal
+    }
+
+    /// The approximate factor by which position errors exceed their nominal
+    /// size, or `None` if unknown.
+    pub fn error_factor(self) -> Option<f64> {
+        match self {
+            DateValidity::Nominal => Some(1.0),
+            DateValidity::Degraded2 => Some(2.0),
+            DateValidity::Degraded10 => Some(10.0),
+            DateValidity::Degraded60 => Some(60.0),
+            DateValidity::Unreliable => None,
+        }
+    }
+}
+
+/// Earth position and velocity, as returned by [`position_velocity_00`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EarthPv {
+    /// How the accuracy for this date compares with that over 1900-2100 AD
+    pub validity: DateValidity,
+    /// Heliocentric Earth position/velocity (AU, AU/day)
+    pub pvh: [[f64; 3]; 2],
+    /// Barycentric Earth position/velocity (AU, AU/day)
+    pub pvb: [[f64; 3]; 2],
+}
 
 /// Earth position and velocity, heliocentric and barycentric, with respect to
 /// the Barycentric Celestial Reference System. (`eraEpv00`)
@@ -10,9 +74,9 @@ use crate::constants::{ERFA_DJ00, ERFA_DJY};
 /// Given:
 /// * `date1`,`date2`: TDB date (Note 1)
 ///
-/// Returned:
-/// * `warning`: `false` = OK,`true` = warning: date outside the range 1900-2100
-///   AD
+/// Returned (as an [`EarthPv`]):
+/// * `validity`: [`DateValidity::Nominal`] if the date is within 1900-2100 AD,
+///   otherwise an estimate of the degradation (Note 4)
 /// * `pvh`: heliocentric Earth position/velocity
 /// * `pvb`: barycentric Earth position/velocity
 ///
@@ -81,7 +145,7 @@ use crate::constants::{ERFA_DJ00, ERFA_DJY};
 ///     and 2500 the deterioration is a factor of 10 and by 1000 and 3000 a
 ///     factor of 60.  The velocity accuracy falls off at about half that rate.
 ///
-pub fn position_velocity_00(date1: f64, date2: f64) -> (bool, [[f64; 3]; 2], [[f64; 3]; 2]) {
+pub fn position_velocity_00(date1: f64, date2: f64) -> EarthPv {
     // AM{12, 13, 21, 22, 23, 32, 33} are matrix elements for orienting the
     // analytical model to DE405.
     //
@@ -153,100 +217,40 @@ pub fn position_velocity_00(date1: f64, date2: f64) -> (bool, [[f64; 3]; 2], [[f
 
     /* Time since reference epoch, Julian years. */
     let t = ((date1 - ERFA_DJ00) + date2) / ERFA_DJY;
-    let t2 = t * t;
 
     /* Set status. */
-    let jstat = t.abs() > 100.0;
+    let validity = DateValidity::from_years(t);
 
     /* X then Y then Z. */
     for i in 0..3 {
         /* Initialize position and velocity component. */
-        let mut xyz = 0.0;
-        let mut xyzd = 0.0;
+        let mut xyz = [Sequential::new(0.0), Sequential::new(0.0)];
 
         /* ------------------------------------------------ */
         /* Obtain component of Sun to Earth ecliptic vector */
         /* ------------------------------------------------ */
 
-        /* Sun to Earth, T^0 terms. */
-        for coeffs in CE0[i].chunks_exact(3) {
-            let a = coeffs[0];
-            let b = coeffs[1];
-            let c = coeffs[2];
-            let p = b + c * t;
-            xyz += a * p.cos();
-            xyzd -= a * c * p.sin();
-        }
-
-        /* Sun to Earth, T^1 terms. */
-        for coeffs in CE1[i].chunks_exact(3) {
-            let a = coeffs[0];
-            let b = coeffs[1];
-            let c = coeffs[2];
-            let ct = c * t;
-            let p = b + ct;
-            let cp = p.cos();
-            xyz += a * t * cp;
-            xyzd += a * (cp - ct * p.sin());
-        }
-
-        /* Sun to Earth, T^2 terms. */
-        for coeffs in CE2[i].chunks_exact(3) {
-            let a = coeffs[0];
-            let b = coeffs[1];
-            let c = coeffs[2];
-            let ct = c * t;
-            let p = b + ct;
-            let cp = p.cos();
-            xyz += a * t2 * cp;
-            xyzd += a * t * (2.0 * cp - ct * p.sin());
-        }
+        /* Sun to Earth, T^0, T^1 and T^2 terms. */
+        accumulate(harmonics::<0>(CE0[i]), t, &[], &mut xyz);
+        accumulate(harmonics::<1>(CE1[i]), t, &[], &mut xyz);
+        accumulate(harmonics::<2>(CE2[i]), t, &[], &mut xyz);
 
         /* Heliocentric Earth position and velocity component. */
-        ph[i] = xyz;
-        vh[i] = xyzd / ERFA_DJY;
+        ph[i] = xyz[0].sum();
+        vh[i] = xyz[1].sum() / ERFA_DJY;
 
         /* ------------------------------------------------ */
         /* Obtain component of SSB to Earth ecliptic vector */
         /* ------------------------------------------------ */
 
-        /* SSB to Sun, T^0 terms. */
-        for coeffs in CS0[i].chunks_exact(3) {
-            let a = coeffs[0];
-            let b = coeffs[1];
-            let c = coeffs[2];
-            let p = b + c * t;
-            xyz += a * p.cos();
-            xyzd -= a * c * p.sin();
-        }
-
-        /* SSB to Sun, T^1 terms. */
-        for coeffs in CS1[i].chunks_exact(3) {
-            let a = coeffs[0];
-            let b = coeffs[1];
-            let c = coeffs[2];
-            let ct = c * t;
-            let p = b + ct;
-            let cp = p.cos();
-            xyz += a * t * cp;
-            xyzd += a * (cp - ct * p.sin());
-        }
-
-        /* SSB to Sun, T^2 terms. */
-        for coeffs in CS2[i].chunks_exact(3) {
-            let a = coeffs[0];
-            let b = coeffs[1];
-            let c = coeffs[2];
-            let ct = c * t;
-            let p = b + ct;
-            let cp = p.cos();
-            xyz += a * t2 * cp;
-            xyzd += a * t * (2.0 * cp - ct * p.sin());
-        }
+        /* SSB to Sun, T^0, T^1 and T^2 terms. */
+        accumulate(harmonics::<0>(CS0[i]), t, &[], &mut xyz);
+        accumulate(harmonics::<1>(CS1[i]), t, &[], &mut xyz);
+        accumulate(harmonics::<2>(CS2[i]), t, &[], &mut xyz);
 
         /* Barycentric Earth position and velocity component. */
-        pb[i] = xyz;
-        vb[i] = xyzd / ERFA_DJY;
+        pb[i] = xyz[0].sum();
+        vb[i] = xyz[1].sum() / ERFA_DJY;
 
         /* Next Cartesian component. */
     }
@@ -284,7 +288,65 @@ pub fn position_velocity_00(date1: f64, date2: f64) -> (bool, [[f64; 3]; 2], [[f
     pvb[1][1] = AM21 * x + AM22 * y + AM23 * z;
     pvb[1][2] = AM32 * y + AM33 * z;
 
-    (jstat, pvh, pvb)
+    EarthPv { validity, pvh, pvb }
+}
+
+/// Earth position and velocity, heliocentric and barycentric, with respect to
+/// the Barycentric Celestial Reference System, refusing dates outside
+/// 1900-2100 AD. (`eraEpv00`)
+///
+/// This is [`position_velocity_00`], except that dates for which ERFA would
+/// set its warning status are an error.
+///
+/// # Errors
+///
+/// Returns [`ErfaError::DateOutOfRange`] if the date is outside the range
+/// 1900-2100 AD.
+///
+pub fn position_velocity_00_strict(date1: f64, date2: f64) -> Result<EarthPv, ErfaError> {
+    let pv = position_velocity_00(date1, date2);
+    if pv.validity.is_nominal() {
+        Ok(pv)
+    } else {
+        Err(ErfaError::DateOutOfRange {
+            function: "position_velocity_00",
+            validity: pv.validity,
+        })
+    }
+}
+
+/// One harmonic term of the ephemeris, `a T^POWER cos(b + c T)`, contributing
+/// to a position component and its rate.
+struct Harmonic<const POWER: u8> {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl<const POWER: u8> PoissonTerm<2> for Harmonic<POWER> {
+    fn argument(&self, t: f64, _args: &[f64]) -> f64 {
+        self.b + self.c * t
+    }
+
+    fn contributions(&self, t: f64, sp: f64, cp: f64) -> [f64; 2] {
+        let Harmonic { a, c, .. } = *self;
+        let ct = c * t;
+        match POWER {
+            0 => [a * cp, -(a * c * sp)],
+            1 => [a * t * cp, a * (cp - ct * sp)],
+            _ => [a * (t * t) * cp, a * t * (2.0 * cp - ct * sp)],
+        }
+    }
+}
+
+/// The terms of a coefficient array, which holds amplitude, phase and
+/// frequency triplets.
+fn harmonics<const POWER: u8>(coeffs: &[f64]) -> impl Iterator<Item = Harmonic<POWER>> + '_ {
+    coeffs.chunks_exact(3).map(|abc| Harmonic {
+        a: abc[0],
+        b: abc[1],
+        c: abc[2],
+    })
 }
 
 const AM12: f64 = 0.000000211284;
@@ -0,0 +1,29 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Variants of the [`earth`](super) functions that take [`Epoch`]s tagged with
+//! the time scale they require. See the untyped functions for full
+//! documentation; the results are identical.
+
+use super::EarthPv;
+use crate::time::{Epoch, TDB};
+
+/// Earth position and velocity, heliocentric and barycentric, with respect to
+/// the Barycentric Celestial Reference System. (`eraEpv00`)
+///
+/// Given:
+/// * `tdb`: TDB epoch (TT can be used instead in most applications; convert
+///   it with [`Epoch::to_tdb`] and a `dtr` of zero)
+///
+/// Returned (as an [`EarthPv`]):
+/// * `validity`: how the accuracy compares with that over 1900-2100 AD
+/// * `pvh`: heliocentric Earth position/velocity
+/// * `pvb`: barycentric Earth position/velocity
+///
+/// See [`position_velocity_00`](super::position_velocity_00).
+///
+pub fn position_velocity_00(tdb: Epoch<TDB>) -> EarthPv {
+    let (date1, date2) = tdb.parts();
+    super::position_velocity_00(date1, date2)
+}
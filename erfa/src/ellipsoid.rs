@@ -4,6 +4,8 @@
 
 //! Ellipsoid code.
 
+use crate::ErfaError;
+
 /// Available ellipsoid models. If in doubt, use `WGS84`.
 #[derive(Clone, Copy, Debug)]
 pub enum Ellipsoid {
@@ -50,3 +52,206 @@ impl Ellipsoid {
         }
     }
 }
+
+impl TryFrom<i32> for Ellipsoid {
+    type Error = ErfaError;
+
+    /// Convert an ERFA ellipsoid identifier (e.g. `ERFA_WGS84`) into an
+    /// [`Ellipsoid`]. This mirrors the identifiers accepted by `eraEform`.
+    fn try_from(n: i32) -> Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Ellipsoid::WGS84),
+            2 => Ok(Ellipsoid::GRS80),
+            3 => Ok(Ellipsoid::WGS72),
+            _ => Err(ErfaError::UnknownEllipsoid(n)),
+        }
+    }
+}
+
+/// Earth reference ellipsoids. (`eraEform`)
+///
+/// Given:
+/// * `n`: ellipsoid identifier (Note 1)
+///
+/// Returned:
+/// * `a`: equatorial radius (meters, Note 2)
+/// * `f`: flattening (Note 2)
+///
+/// # Errors
+///
+/// This function will return an error if `n` is not a known ellipsoid
+/// identifier.
+///
+/// # Notes:
+///
+/// 1) The identifier `n` is a number that specifies the choice of reference
+///    ellipsoid.  The following are supported:
+///
+///    | `n` | ellipsoid |
+///    |-----|-----------|
+///    |  1  | WGS84     |
+///    |  2  | GRS80     |
+///    |  3  | WGS72     |
+///
+///    The number `n` has no significance outside the ERFA software.  For
+///    convenience, [`Ellipsoid`] can be cast to the corresponding identifier.
+///
+/// 2) The ellipsoid parameters are returned in the form of equatorial radius in
+///    meters (`a`) and flattening (`f`).  The latter is a number around
+///    0.00335, i.e. around 1/298.
+///
+pub fn ellipsoid_form(n: i32) -> Result<(f64, f64), ErfaError> {
+    Ellipsoid::try_from(n).map(Ellipsoid::get_params)
+}
+
+/// A reference ellipsoid of arbitrary form, described by its equatorial radius
+/// (`a`) and flattening (`f`).
+///
+/// The standard ellipsoids are available via `From<Ellipsoid>`; anything else
+/// (e.g. a planetary body, or a historical Earth ellipsoid) can be made with
+/// [`ReferenceEllipsoid::new`]. The geodetic transforms in
+/// [`transform`](crate::transform) accept either.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReferenceEllipsoid {
+    a: f64,
+    f: f64,
+}
+
+impl ReferenceEllipsoid {
+    /// Create a new [`ReferenceEllipsoid`] from its equatorial radius `a` and
+    /// flattening `f`. The equatorial radius can be in any units, but meters is
+    /// the conventional choice; it determines the units of the other lengths
+    /// used with this ellipsoid.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `a` or `f` are invalid; valid
+    /// values are finite `a` > 0 and 0 <= `f` < 1.
+    pub fn new(a: f64, f: f64) -> Result<Self, ErfaError> {
+        if !(0.0..1.0).contains(&f) {
+            return Err(ErfaError::InvalidFlattening {
+                function: "ReferenceEllipsoid::new",
+                f,
+            });
+        }
+        if !(a.is_finite() && a > 0.0) {
+            return Err(ErfaError::InvalidEquatorialRadius {
+                function: "ReferenceEllipsoid::new",
+                a,
+            });
+        }
+
+        Ok(Self { a, f })
+    }
+
+    /// Get the parameters of this ellipsoid in the form of equatorial radius
+    /// (`a`) and flattening (`f`).
+    pub fn get_params(self) -> (f64, f64) {
+        (self.a, self.f)
+    }
+
+    /// The equatorial radius (semi-major axis) `a`.
+    pub fn equatorial_radius(self) -> f64 {
+        self.a
+    }
+
+    /// The flattening `f = (a - b) / a`.
+    pub fn flattening(self) -> f64 {
+        self.f
+    }
+
+    /// The polar radius (semi-minor axis) `b = a * (1 - f)`.
+    pub fn polar_radius(self) -> f64 {
+        self.a * (1.0 - self.f)
+    }
+
+    /// The square of the first eccentricity, `e^2 = (2 - f) * f`.
+    pub fn eccentricity_squared(self) -> f64 {
+        (2.0 - self.f) * self.f
+    }
+
+    /// The square of the second eccentricity, `e'^2 = e^2 / (1 - e^2)`.
+    pub fn second_eccentricity_squared(self) -> f64 {
+        let e2 = self.eccentricity_squared();
+        e2 / (1.0 - e2)
+    }
+}
+
+impl From<Ellipsoid> for ReferenceEllipsoid {
+    fn from(e: Ellipsoid) -> Self {
+        let (a, f) = e.get_params();
+        Self { a, f }
+    }
+}
+
+impl Default for ReferenceEllipsoid {
+    fn default() -> Self {
+        Ellipsoid::default().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_try_from_matches_erfa_ids() {
+        for (n, e) in [
+            (erfa_sys::ERFA_WGS84, Ellipsoid::WGS84),
+            (erfa_sys::ERFA_GRS80, Ellipsoid::GRS80),
+            (erfa_sys::ERFA_WGS72, Ellipsoid::WGS72),
+        ] {
+            let result = Ellipsoid::try_from(n).unwrap();
+            assert_eq!(result as i32, e as i32);
+        }
+        assert!(Ellipsoid::try_from(0).is_err());
+        assert!(Ellipsoid::try_from(4).is_err());
+    }
+
+    #[test]
+    fn test_derived_quantities() {
+        let wgs84 = ReferenceEllipsoid::from(Ellipsoid::WGS84);
+        assert_abs_diff_eq!(wgs84.polar_radius(), 6356752.314245, epsilon = 1e-6);
+        assert_abs_diff_eq!(
+            wgs84.eccentricity_squared(),
+            6.69437999014e-3,
+            epsilon = 1e-14
+        );
+        assert_abs_diff_eq!(
+            wgs84.second_eccentricity_squared(),
+            6.73949674228e-3,
+            epsilon = 1e-14
+        );
+    }
+
+    #[test]
+    fn test_custom_ellipsoid_in_transforms() {
+        use crate::transform::{geocentric_to_geodetic, geodetic_to_geocentric};
+
+        let (a, f) = Ellipsoid::GRS80.get_params();
+        let custom = ReferenceEllipsoid::new(a, f).unwrap();
+        let xyz = geodetic_to_geocentric(custom, 0.1, 0.2, 300.0).unwrap();
+        let expected = geodetic_to_geocentric(Ellipsoid::GRS80, 0.1, 0.2, 300.0).unwrap();
+        assert_abs_diff_eq!(xyz.as_slice(), expected.as_slice());
+
+        let result = geocentric_to_geodetic(custom, xyz).unwrap();
+        assert_abs_diff_eq!(
+            result.as_slice(),
+            [0.1, 0.2, 300.0].as_slice(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_new_validates() {
+        assert!(ReferenceEllipsoid::new(6378137.0, 1.0 / 298.257223563).is_ok());
+        assert!(ReferenceEllipsoid::new(0.0, 0.0).is_err());
+        assert!(ReferenceEllipsoid::new(-1.0, 0.0).is_err());
+        assert!(ReferenceEllipsoid::new(1.0, 1.0).is_err());
+        assert!(ReferenceEllipsoid::new(1.0, -0.1).is_err());
+        assert!(ReferenceEllipsoid::new(f64::NAN, 0.0).is_err());
+        assert!(ReferenceEllipsoid::new(f64::INFINITY, 0.0).is_err());
+    }
+}
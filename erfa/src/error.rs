@@ -0,0 +1,534 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Errors, warnings and ERFA status codes.
+//!
+//! ERFA functions report problems with an integer status: negative values are
+//! errors (no result is produced), positive values are warnings (a result is
+//! produced, but it may be dubious). The meaning of each code is specific to
+//! the function, so the conversions here take the name of the ERFA C function
+//! (e.g. "eraGc2gde") that the status belongs to. For every code documented by
+//! ERFA, converting to a Rust error or warning and back gives the original
+//! code; undocumented codes are preserved as [`ErfaError::Status`] or
+//! [`ErfaWarning::Status`].
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::earth::DateValidity;
+
+/// An error from an ERFA function.
+///
+/// More variants may be added (e.g. `ErfaError::Io` exists only with the
+/// `std` feature), so matches on this enum need a wildcard arm.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErfaError {
+    InvalidValue {
+        function: &'static str,
+        value: &'static str,
+    },
+
+    Unrealistic {
+        function: &'static str,
+    },
+
+    InvalidFlattening {
+        function: &'static str,
+        f: f64,
+    },
+
+    InvalidEquatorialRadius {
+        function: &'static str,
+        a: f64,
+    },
+
+    UnknownEllipsoid(i32),
+
+    DateOutOfRange {
+        function: &'static str,
+        validity: DateValidity,
+    },
+
+    InvalidDate {
+        function: &'static str,
+    },
+
+    Internal {
+        function: &'static str,
+    },
+
+    Warning {
+        function: &'static str,
+        warning: ErfaWarning,
+    },
+
+    Status {
+        function: &'static str,
+        status: i32,
+    },
+
+    InvalidSeries {
+        line: usize,
+        reason: &'static str,
+    },
+
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+}
+
+impl core::fmt::Display for ErfaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ErfaError::InvalidValue { function, value } => {
+                write!(f, "Function {function} indicated that value '{value}' is invalid")
+            }
+            ErfaError::Unrealistic { function } => {
+                write!(f, "Function {function} indicated that it received unrealistic inputs")
+            }
+            ErfaError::InvalidFlattening { function, f: flattening } => write!(
+                f,
+                "Function {function} received an invalid flattening ({flattening}); valid values are 0 <= f < 1"
+            ),
+            ErfaError::InvalidEquatorialRadius { function, a } => write!(
+                f,
+                "Function {function} received an invalid equatorial radius ({a}); valid values are a > 0"
+            ),
+            ErfaError::UnknownEllipsoid(n) => write!(f, "Ellipsoid identifier {n} is not known"),
+            ErfaError::DateOutOfRange { function, validity } => write!(
+                f,
+                "Function {function} received a date outside its valid range ({validity:?})"
+            ),
+            ErfaError::InvalidDate { function } => {
+                write!(f, "Function {function} received an unacceptable date")
+            }
+            ErfaError::Internal { function } => {
+                write!(f, "Function {function} encountered an internal error")
+            }
+            ErfaError::Warning { function, warning } => write!(
+                f,
+                "Function {function} treated a warning as an error: {warning}"
+            ),
+            ErfaError::Status { function, status } => {
+                write!(f, "Function {function} returned status {status}")
+            }
+            ErfaError::InvalidSeries { line, reason } => {
+                write!(f, "Line {line} of the series table is invalid: {reason}")
+            }
+            #[cfg(feature = "std")]
+            ErfaError::Io(e) => write!(f, "Couldn't read the series table: {e}"),
+        }
+    }
+}
+
+impl core::error::Error for ErfaError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            ErfaError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for ErfaError {
+    fn from(e: std::io::Error) -> Self {
+        ErfaError::Io(e)
+    }
+}
+
+/// A condition that doesn't prevent a result from being produced, but
+/// indicates that the result may be less accurate than usual.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErfaWarning {
+    /// The year is outside the range covered by the leap-second table (or is
+    /// in the future), so the result may not be reliable.
+    DubiousYear,
+
+    /// The date is outside the range over which the model's accuracy is
+    /// quoted.
+    DateOutsideRange,
+
+    /// The time is after the end of the day (for a day without a leap second).
+    TimeAfterEndOfDay,
+
+    /// The distance was overridden (e.g. a zero or negative parallax was
+    /// replaced by a default).
+    DistanceOverridden,
+
+    /// The speed was excessive and has been capped.
+    ExcessiveSpeed,
+
+    /// An iterative solution didn't converge.
+    NotConverged,
+
+    /// A positive status code with no more specific meaning.
+    Status(i32),
+}
+
+impl core::fmt::Display for ErfaWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ErfaWarning::DubiousYear => write!(f, "dubious year"),
+            ErfaWarning::DateOutsideRange => write!(f, "date outside the model's range"),
+            ErfaWarning::TimeAfterEndOfDay => write!(f, "time is after end of day"),
+            ErfaWarning::DistanceOverridden => write!(f, "distance overridden"),
+            ErfaWarning::ExcessiveSpeed => write!(f, "excessive speed"),
+            ErfaWarning::NotConverged => write!(f, "solution didn't converge"),
+            ErfaWarning::Status(s) => write!(f, "status {s}"),
+        }
+    }
+}
+
+/// A result with any warnings that were raised in producing it.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Warned<T> {
+    pub value: T,
+    pub warnings: Vec<ErfaWarning>,
+}
+
+/// ERFA functions that flag dubious years with a status of +1 and
+/// unacceptable dates with -1.
+const DUBIOUS_YEAR_FUNCTIONS: [&str; 12] = [
+    "eraDat",
+    "eraUtctai",
+    "eraTaiutc",
+    "eraUtcut1",
+    "eraUt1utc",
+    "eraD2dtf",
+    "eraAtco13",
+    "eraAtio13",
+    "eraAtoc13",
+    "eraAtoi13",
+    "eraApco13",
+    "eraApio13",
+];
+
+/// The warnings that `function` may raise, with their status codes. Codes
+/// that are powers of two can be combined.
+fn warning_codes(function: &str) -> &'static [(i32, ErfaWarning)] {
+    match function {
+        "eraStarpv" | "eraStarpm" | "eraPmsafe" => &[
+            (1, ErfaWarning::DistanceOverridden),
+            (2, ErfaWarning::ExcessiveSpeed),
+            (4, ErfaWarning::NotConverged),
+        ],
+        "eraDtf2d" => &[
+            (1, ErfaWarning::DubiousYear),
+            (2, ErfaWarning::TimeAfterEndOfDay),
+        ],
+        "eraEpv00" => &[(1, ErfaWarning::DateOutsideRange)],
+        "eraPlan94" => &[
+            (1, ErfaWarning::DateOutsideRange),
+            (2, ErfaWarning::NotConverged),
+        ],
+        f if DUBIOUS_YEAR_FUNCTIONS.contains(&f) => &[(1, ErfaWarning::DubiousYear)],
+        _ => &[],
+    }
+}
+
+/// The arguments that `function` range-checks, in status code order, and the
+/// sign of those codes.
+fn checked_values(function: &str) -> (&'static [&'static str], i32) {
+    match function {
+        "eraCal2jd" => (&["iy", "im", "id"], -1),
+        "eraDat" => (&["iy", "im", "id", "fd"], -1),
+        "eraDtf2d" => (&["iy", "im", "id", "ihr", "imn", "sec"], -1),
+        "eraPlan94" => (&["np"], -1),
+        "eraAf2a" => (&["ideg", "iamin", "asec"], 1),
+        "eraTf2a" | "eraTf2d" => (&["ihour", "imin", "sec"], 1),
+        _ => (&[], -1),
+    }
+}
+
+impl ErfaError {
+    /// Interpret a status code returned by the ERFA C function `function`.
+    /// Returns `None` if the status doesn't indicate an error (i.e. it's zero
+    /// or a warning).
+    ///
+    /// Values that aren't conveyed by the status (e.g. the offending
+    /// flattening) are set to NaN or zero.
+    pub fn from_status(function: &'static str, status: i32) -> Option<ErfaError> {
+        let (values, sign) = checked_values(function);
+        let index = status * sign - 1;
+        if status != 0 && (0..values.len() as i32).contains(&index) {
+            return Some(ErfaError::InvalidValue {
+                function,
+                value: values[index as usize],
+            });
+        }
+
+        Some(match (function, status) {
+            (_, s) if s >= 0 => return None,
+            ("eraGc2gde", -1) => ErfaError::InvalidFlattening {
+                function,
+                f: f64::NAN,
+            },
+            ("eraGc2gde", -2) => ErfaError::InvalidEquatorialRadius {
+                function,
+                a: f64::NAN,
+            },
+            ("eraGc2gd" | "eraGd2gc" | "eraEform", -1) => ErfaError::UnknownEllipsoid(0),
+            ("eraGd2gc", -2) | ("eraGd2gce", -1) => ErfaError::Unrealistic { function },
+            ("eraGc2gd", -2) | ("eraDat", -5) | ("eraStarpm" | "eraPmsafe", -1) => {
+                ErfaError::Internal { function }
+            }
+            ("eraJd2cal", -1) => ErfaError::InvalidDate { function },
+            (f, -1) if DUBIOUS_YEAR_FUNCTIONS.contains(&f) => ErfaError::InvalidDate { function },
+            (_, status) => ErfaError::Status { function, status },
+        })
+    }
+
+    /// The status code that the ERFA C function `function` returns for this
+    /// error.
+    pub fn to_status(&self, function: &str) -> i32 {
+        match self {
+            ErfaError::InvalidValue { value, .. } => {
+                let (values, sign) = checked_values(function);
+                match values.iter().position(|v| v == value) {
+                    Some(i) => sign * (i as i32 + 1),
+                    None => -1,
+                }
+            }
+            ErfaError::Unrealistic { .. } if function == "eraGd2gc" => -2,
+            ErfaError::Unrealistic { .. } => -1,
+            ErfaError::InvalidFlattening { .. } => -1,
+            ErfaError::InvalidEquatorialRadius { .. } => -2,
+            ErfaError::UnknownEllipsoid(_) => -1,
+            ErfaError::DateOutOfRange { .. } => 1,
+            ErfaError::InvalidDate { .. } => -1,
+            ErfaError::Internal { .. } => match function {
+                "eraGc2gd" => -2,
+                "eraDat" => -5,
+                _ => -1,
+            },
+            ErfaError::Warning { warning, .. } => ErfaWarning::to_status(&[*warning], function),
+            ErfaError::Status { status, .. } => *status,
+            ErfaError::InvalidSeries { .. } => -1,
+            #[cfg(feature = "std")]
+            ErfaError::Io(_) => -1,
+        }
+    }
+}
+
+impl ErfaWarning {
+    /// Interpret a status code returned by the ERFA C function `function`.
+    /// Zero and error statuses give no warnings.
+    #[cfg(feature = "alloc")]
+    pub fn from_status(function: &str, status: i32) -> Vec<ErfaWarning> {
+        let (values, sign) = checked_values(function);
+        if status <= 0 || (sign > 0 && status as usize <= values.len()) {
+            return vec![];
+        }
+
+        let codes = warning_codes(function);
+        let mut warnings = vec![];
+        let mut remaining = status;
+        if let Some((_, w)) = codes.iter().find(|(c, _)| *c == status) {
+            /* An exact match; this covers codes that aren't flags. */
+            warnings.push(*w);
+            remaining = 0;
+        } else {
+            for (c, w) in codes {
+                if c.count_ones() == 1 && remaining & c != 0 {
+                    warnings.push(*w);
+                    remaining &= !c;
+                }
+            }
+        }
+        if remaining != 0 {
+            warnings.push(ErfaWarning::Status(remaining));
+        }
+        warnings
+    }
+
+    /// The status code that the ERFA C function `function` returns for these
+    /// warnings.
+    pub fn to_status(warnings: &[ErfaWarning], function: &str) -> i32 {
+        let codes = warning_codes(function);
+        warnings
+            .iter()
+            .map(|w| match w {
+                ErfaWarning::Status(s) => *s,
+                w => codes
+                    .iter()
+                    .find(|(_, cw)| cw == w)
+                    .map(|(c, _)| *c)
+                    .unwrap_or(1),
+            })
+            .fold(0, |acc, c| acc | c)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Warned<T> {
+    /// A result without warnings.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            warnings: vec![],
+        }
+    }
+
+    /// Add a warning to the result.
+    pub fn with_warning(mut self, warning: ErfaWarning) -> Self {
+        self.warnings.push(warning);
+        self
+    }
+
+    /// Were no warnings raised?
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// The result, ignoring any warnings.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// The result, treating the first warning (if any) as an error.
+    pub fn strict(self, function: &'static str) -> Result<T, ErfaError> {
+        match self.warnings.first() {
+            None => Ok(self.value),
+            Some(&warning) => Err(ErfaError::Warning { function, warning }),
+        }
+    }
+
+    /// Transform the result, keeping the warnings.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Warned<U> {
+        Warned {
+            value: f(self.value),
+            warnings: self.warnings,
+        }
+    }
+
+    /// Combine a value with the status returned by the ERFA C function
+    /// `function` that produced it.
+    pub fn from_status(function: &'static str, status: i32, value: T) -> Result<Self, ErfaError> {
+        match ErfaError::from_status(function, status) {
+            Some(e) => Err(e),
+            None => Ok(Self {
+                value,
+                warnings: ErfaWarning::from_status(function, status),
+            }),
+        }
+    }
+
+    /// The status code that the ERFA C function `function` returns alongside
+    /// this result.
+    pub fn status(&self, function: &str) -> i32 {
+        ErfaWarning::to_status(&self.warnings, function)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every non-zero status documented by ERFA for the functions with
+    /// interesting status handling.
+    #[cfg(feature = "alloc")]
+    const DOCUMENTED: &[(&str, &[i32])] = &[
+        ("eraAf2a", &[1, 2, 3]),
+        ("eraTf2a", &[1, 2, 3]),
+        ("eraCal2jd", &[-1, -2, -3]),
+        ("eraDat", &[1, -1, -2, -3, -4, -5]),
+        ("eraDtf2d", &[3, 2, 1, -1, -2, -3, -4, -5, -6]),
+        ("eraUtctai", &[1, -1]),
+        ("eraJd2cal", &[-1]),
+        ("eraEpv00", &[1]),
+        ("eraPlan94", &[-1, 1, 2]),
+        ("eraStarpv", &[1, 2, 3, 4, 5, 6, 7]),
+        ("eraStarpm", &[-1, 1, 2, 3, 4, 5, 6, 7]),
+        ("eraPmsafe", &[-1, 1, 2, 4, 7]),
+        ("eraGc2gd", &[-1, -2]),
+        ("eraGc2gde", &[-1, -2]),
+        ("eraGd2gc", &[-1, -2]),
+        ("eraGd2gce", &[-1]),
+        ("eraEform", &[-1]),
+        // A function with no special handling.
+        ("eraFoo", &[-3, 5]),
+    ];
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_status_round_trip() {
+        for (function, codes) in DOCUMENTED {
+            for &status in *codes {
+                let round_trip = match Warned::from_status(function, status, ()) {
+                    Ok(w) => {
+                        assert!(!w.is_clean(), "{function} {status}");
+                        w.status(function)
+                    }
+                    Err(e) => e.to_status(function),
+                };
+                assert_eq!(round_trip, status, "{function}");
+            }
+            assert!(Warned::from_status(function, 0, ()).unwrap().is_clean());
+        }
+    }
+
+    #[test]
+    fn test_status_meanings() {
+        assert!(matches!(
+            ErfaError::from_status("eraGc2gde", -2),
+            Some(ErfaError::InvalidEquatorialRadius { .. })
+        ));
+        assert!(matches!(
+            ErfaError::from_status("eraDat", -4),
+            Some(ErfaError::InvalidValue { value: "fd", .. })
+        ));
+        assert!(matches!(
+            ErfaError::from_status("eraUtctai", -1),
+            Some(ErfaError::InvalidDate { .. })
+        ));
+
+        // Errors raised by the Rust functions map to the ERFA codes.
+        let e = crate::transform::geocentric_to_geodetic_inner(0.0, 0.003, [1.0; 3]).unwrap_err();
+        assert_eq!(e.to_status("eraGc2gde"), -2);
+        let e = crate::angle::Angle::from_dms(false, 0, 60, 0.0).unwrap_err();
+        assert_eq!(e.to_status("eraAf2a"), 2);
+        let e = crate::ellipsoid::ellipsoid_form(4).unwrap_err();
+        assert_eq!(e.to_status("eraEform"), -1);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_warning_meanings() {
+        assert_eq!(
+            ErfaWarning::from_status("eraStarpm", 5),
+            vec![ErfaWarning::DistanceOverridden, ErfaWarning::NotConverged]
+        );
+        assert_eq!(
+            ErfaWarning::from_status("eraDat", 1),
+            vec![ErfaWarning::DubiousYear]
+        );
+        assert_eq!(
+            ErfaWarning::from_status("eraPlan94", 2),
+            vec![ErfaWarning::NotConverged]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_warned() {
+        let w = Warned::new(1.5);
+        assert!(w.is_clean());
+        assert_eq!(w.clone().strict("f").unwrap(), 1.5);
+
+        let w = w.with_warning(ErfaWarning::DubiousYear).map(|x| x * 2.0);
+        assert_eq!(w.value, 3.0);
+        assert_eq!(w.status("eraDat"), 1);
+        assert!(matches!(
+            w.clone().strict("f"),
+            Err(ErfaError::Warning {
+                warning: ErfaWarning::DubiousYear,
+                ..
+            })
+        ));
+        assert_eq!(w.into_inner(), 3.0);
+    }
+}
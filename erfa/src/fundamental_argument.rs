@@ -4,9 +4,10 @@
 
 //! Fundamental argument code.
 
-use std::ops::Rem;
-
-use crate::constants::*;
+use crate::{
+    constants::*,
+    real::{polynomial, Real},
+};
 
 /// Fundamental argument, IERS Conventions (2003): mean anomaly of the Moon.
 /// (`eraFal03`)
@@ -33,16 +34,17 @@ use crate::constants::*;
 /// * Simon, J.-L., Bretagnon, P., Chapront, J., Chapront-Touze, M., Francou,
 ///   G., Laskar, J. 1994, Astron.Astrophys. 282, 663-683
 ///
-pub fn l03(t: f64) -> f64 {
+pub fn l03<R: Real>(t: R) -> R {
     /* Mean anomaly of the Moon (IERS Conventions 2003). */
     #[rustfmt::skip]
-    let a =
-        (     485868.249036  +
-    t * ( 1717915923.2178 +
-    t * (         31.8792 +
-    t * (          0.051635 +
-    t * (        - 0.00024470 ) ) ) ) ).rem(ERFA_TURNAS) * ERFA_DAS2R;
-    a
+    let a = polynomial(t, &[
+              485868.249036,
+          1717915923.2178,
+                  31.8792,
+                   0.051635,
+                 - 0.00024470,
+    ]);
+    a % ERFA_TURNAS * ERFA_DAS2R
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean anomaly of the Sun. (`eraFalp03`)
@@ -69,16 +71,17 @@ pub fn l03(t: f64) -> f64 {
 /// * Simon, J.-L., Bretagnon, P., Chapront, J., Chapront-Touze, M., Francou,
 ///   G., Laskar, J. 1994, Astron.Astrophys. 282, 663-683
 ///
-pub fn lp03(t: f64) -> f64 {
+pub fn lp03<R: Real>(t: R) -> R {
     /* Mean anomaly of the Sun (IERS Conventions 2003). */
     #[rustfmt::skip]
-   let a =
-       (         1287104.793048 +
-         t * ( 129596581.0481 +
-         t * (       - 0.5532 +
-         t * (         0.000136 +
-         t * (       - 0.00001149 ) ) ) ) ).rem(ERFA_TURNAS) * ERFA_DAS2R;
-    a
+    let a = polynomial(t, &[
+             1287104.793048,
+           129596581.0481,
+                 - 0.5532,
+                   0.000136,
+                 - 0.00001149,
+    ]);
+    a % ERFA_TURNAS * ERFA_DAS2R
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean longitude of the Moon
@@ -106,17 +109,18 @@ pub fn lp03(t: f64) -> f64 {
 /// * Simon, J.-L., Bretagnon, P., Chapront, J., Chapront-Touze, M., Francou,
 ///   G., Laskar, J. 1994, Astron.Astrophys. 282, 663-683
 ///
-pub fn f03(t: f64) -> f64 {
+pub fn f03<R: Real>(t: R) -> R {
     /* Mean longitude of the Moon minus that of the ascending node */
     /* (IERS Conventions 2003).                                    */
     #[rustfmt::skip]
-   let a =
-   (           335779.526232 +
-     t * ( 1739527262.8478 +
-     t * (       - 12.7512 +
-     t * (        - 0.001037 +
-     t * (          0.00000417 ) ) ) ) ).rem(ERFA_TURNAS) * ERFA_DAS2R;
-    a
+    let a = polynomial(t, &[
+              335779.526232,
+          1739527262.8478,
+                - 12.7512,
+                 - 0.001037,
+                   0.00000417,
+    ]);
+    a % ERFA_TURNAS * ERFA_DAS2R
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean elongation of the Moon
@@ -144,16 +148,17 @@ pub fn f03(t: f64) -> f64 {
 /// * Simon, J.-L., Bretagnon, P., Chapront, J., Chapront-Touze, M., Francou,
 ///   G., Laskar, J. 1994, Astron.Astrophys. 282, 663-683
 ///
-pub fn d03(t: f64) -> f64 {
+pub fn d03<R: Real>(t: R) -> R {
     /* Mean elongation of the Moon from the Sun (IERS Conventions 2003). */
     #[rustfmt::skip]
-    let a = ((   1072260.703692 +
-        t * ( 1602961601.2090 +
-        t * (        - 6.3706 +
-        t * (          0.006593 +
-        t * (        - 0.00003169 ) ) ) )) % ERFA_TURNAS )
-        * ERFA_DAS2R;
-    a
+    let a = polynomial(t, &[
+             1072260.703692,
+          1602961601.2090,
+                 - 6.3706,
+                   0.006593,
+                 - 0.00003169,
+    ]);
+    a % ERFA_TURNAS * ERFA_DAS2R
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean longitude of the Moon's
@@ -181,17 +186,18 @@ pub fn d03(t: f64) -> f64 {
 /// * Simon, J.-L., Bretagnon, P., Chapront, J., Chapront-Touze, M., Francou,
 ///   G., Laskar, J., 1994, Astron.Astrophys. 282, 663-683.
 ///
-pub fn om03(t: f64) -> f64 {
+pub fn om03<R: Real>(t: R) -> R {
     /* Mean longitude of the Moon's ascending node */
     /* (IERS Conventions 2003).                    */
     #[rustfmt::skip]
-    let a =
-    ((          450160.398036 +
-       t * ( - 6962890.5431 +
-       t * (         7.4722 +
-       t * (         0.007702 +
-       t * (       - 0.00005939 ) ) ) )) % ERFA_TURNAS ) * ERFA_DAS2R;
-    a
+    let a = polynomial(t, &[
+              450160.398036,
+           - 6962890.5431,
+                   7.4722,
+                   0.007702,
+                 - 0.00005939,
+    ]);
+    a % ERFA_TURNAS * ERFA_DAS2R
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean longitude of Mercury.
@@ -222,9 +228,9 @@ pub fn om03(t: f64) -> f64 {
 /// * Souchay, J., Loysel, B., Kinoshita, H., Folgueira, M. 1999,
 ///   Astron.Astrophys.Supp.Ser. 135, 111
 ///
-pub fn me03(t: f64) -> f64 {
+pub fn me03<R: Real>(t: R) -> R {
     /* Mean longitude of Mercury (IERS Conventions 2003). */
-    (4.402608842 + 2608.7903141574 * t) % ERFA_D2PI
+    polynomial(t, &[4.402608842, 2608.7903141574]) % ERFA_D2PI
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean longitude of Venus.
@@ -255,9 +261,9 @@ pub fn me03(t: f64) -> f64 {
 /// * Souchay, J., Loysel, B., Kinoshita, H., Folgueira, M. 1999,
 ///   Astron.Astrophys.Supp.Ser. 135, 111
 ///
-pub fn ve03(t: f64) -> f64 {
+pub fn ve03<R: Real>(t: R) -> R {
     /* Mean longitude of Venus (IERS Conventions 2003). */
-    (3.176146697 + 1021.3285546211 * t) % ERFA_D2PI
+    polynomial(t, &[3.176146697, 1021.3285546211]) % ERFA_D2PI
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean longitude of Earth.
@@ -288,9 +294,9 @@ pub fn ve03(t: f64) -> f64 {
 /// * Souchay, J., Loysel, B., Kinoshita, H., Folgueira, M. 1999,
 ///   Astron.Astrophys.Supp.Ser. 135, 111
 ///
-pub fn e03(t: f64) -> f64 {
+pub fn e03<R: Real>(t: R) -> R {
     /* Mean longitude of Earth (IERS Conventions 2003). */
-    (1.753470314 + 628.3075849991 * t) % ERFA_D2PI
+    polynomial(t, &[1.753470314, 628.3075849991]) % ERFA_D2PI
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean longitude of Mars.
@@ -321,9 +327,9 @@ pub fn e03(t: f64) -> f64 {
 /// * Souchay, J., Loysel, B., Kinoshita, H., Folgueira, M. 1999,
 ///   Astron.Astrophys.Supp.Ser. 135, 111
 ///
-pub fn ma03(t: f64) -> f64 {
+pub fn ma03<R: Real>(t: R) -> R {
     /* Mean longitude of Mars (IERS Conventions 2003). */
-    (6.203480913 + 334.0612426700 * t) % ERFA_D2PI
+    polynomial(t, &[6.203480913, 334.0612426700]) % ERFA_D2PI
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean longitude of Jupiter.
@@ -354,9 +360,9 @@ pub fn ma03(t: f64) -> f64 {
 /// * Souchay, J., Loysel, B., Kinoshita, H., Folgueira, M. 1999,
 ///   Astron.Astrophys.Supp.Ser. 135, 111
 ///
-pub fn ju03(t: f64) -> f64 {
+pub fn ju03<R: Real>(t: R) -> R {
     /* Mean longitude of Jupiter (IERS Conventions 2003). */
-    (0.599546497 + 52.9690962641 * t) % ERFA_D2PI
+    polynomial(t, &[0.599546497, 52.9690962641]) % ERFA_D2PI
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean longitude of Saturn.
@@ -387,9 +393,9 @@ pub fn ju03(t: f64) -> f64 {
 /// * Souchay, J., Loysel, B., Kinoshita, H., Folgueira, M. 1999,
 ///   Astron.Astrophys.Supp.Ser. 135, 111
 ///
-pub fn sa03(t: f64) -> f64 {
+pub fn sa03<R: Real>(t: R) -> R {
     /* Mean longitude of Saturn (IERS Conventions 2003). */
-    (0.874016757 + 21.3299104960 * t) % ERFA_D2PI
+    polynomial(t, &[0.874016757, 21.3299104960]) % ERFA_D2PI
 }
 
 /// Fundamental argument, IERS Conventions (2003): mean longitude of Uranus.
@@ -417,9 +423,39 @@ pub fn sa03(t: f64) -> f64 {
 /// * Simon, J.-L., Bretagnon, P., Chapront, J., Chapront-Touze, M., Francou,
 ///   G., Laskar, J. 1994, Astron.Astrophys. 282, 663-683
 ///
-pub fn ur03(t: f64) -> f64 {
+pub fn ur03<R: Real>(t: R) -> R {
     /* Mean longitude of Uranus (IERS Conventions 2003). */
-    (5.481293872 + 7.4781598567 * t) % ERFA_D2PI
+    polynomial(t, &[5.481293872, 7.4781598567]) % ERFA_D2PI
+}
+
+/// Fundamental argument, IERS Conventions (2003): mean longitude of Neptune.
+/// (`eraFane03`)
+///
+/// Given:
+///  * `t`: TDB, Julian centuries since J2000.0 (Note 1)
+///
+/// Returned:
+///  * mean longitude of Neptune, radians (Note 2)
+///
+/// # Notes:
+///
+/// 1) Though t is strictly TDB, it is usually more convenient to use TT, which
+///    makes no significant difference.
+///
+/// 2) The expression used is as adopted in IERS Conventions (2003) and is
+///    adapted from Simon et al. (1994).
+///
+/// # References:
+///
+/// * McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003), IERS Technical
+///   Note No. 32, BKG (2004)
+///
+/// * Simon, J.-L., Bretagnon, P., Chapront, J., Chapront-Touze, M., Francou,
+///   G., Laskar, J. 1994, Astron.Astrophys. 282, 663-683
+///
+pub fn ne03<R: Real>(t: R) -> R {
+    /* Mean longitude of Neptune (IERS Conventions 2003). */
+    polynomial(t, &[5.311886287, 3.8133035638]) % ERFA_D2PI
 }
 
 /// Fundamental argument, IERS Conventions (2003): general accumulated
@@ -450,7 +486,91 @@ pub fn ur03(t: f64) -> f64 {
 /// * McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003), IERS Technical
 ///   Note No. 32, BKG (2004)
 ///
-pub fn pa03(t: f64) -> f64 {
+pub fn pa03<R: Real>(t: R) -> R {
     /* General accumulated precession in longitude. */
-    (0.024381750 + 0.00000538691 * t) * t
+    polynomial(t, &[0.024381750, 0.00000538691]) * t
+}
+
+/// The fundamental arguments of the IERS Conventions (2003) for one date,
+/// computed once so that they can be shared by the functions that need them
+/// (e.g. [`nut00a_with_args`](crate::prenut::nut00a_with_args),
+/// [`S06_with_args`](crate::time::S06_with_args),
+/// [`pn_matrix_06a_with_args`](crate::prenut::pn_matrix_06a_with_args) and
+/// [`gst06a_with_args`](crate::time::gst06a_with_args)).
+///
+/// The `*_with_args` functions give identical results to their counterparts
+/// that take a 2-part Julian Date.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FundamentalArguments<R = f64> {
+    /// TT (strictly TDB), Julian centuries since J2000.0.
+    pub t: R,
+    /// Mean anomaly of the Moon ([`l03`]).
+    pub l: R,
+    /// Mean anomaly of the Sun ([`lp03`]).
+    pub lp: R,
+    /// Mean longitude of the Moon minus that of the ascending node ([`f03`]).
+    pub f: R,
+    /// Mean elongation of the Moon from the Sun ([`d03`]).
+    pub d: R,
+    /// Mean longitude of the Moon's ascending node ([`om03`]).
+    pub om: R,
+    /// Mean longitude of Mercury ([`me03`]).
+    pub me: R,
+    /// Mean longitude of Venus ([`ve03`]).
+    pub ve: R,
+    /// Mean longitude of Earth ([`e03`]).
+    pub e: R,
+    /// Mean longitude of Mars ([`ma03`]).
+    pub ma: R,
+    /// Mean longitude of Jupiter ([`ju03`]).
+    pub ju: R,
+    /// Mean longitude of Saturn ([`sa03`]).
+    pub sa: R,
+    /// Mean longitude of Uranus ([`ur03`]).
+    pub ur: R,
+    /// Mean longitude of Neptune ([`ne03`]).
+    pub ne: R,
+    /// General accumulated precession in longitude ([`pa03`]).
+    pub pa: R,
+}
+
+impl<R: Real> FundamentalArguments<R> {
+    /// Compute the fundamental arguments for the TT 2-part Julian Date
+    /// `date1`,`date2`.
+    pub fn new(date1: R, date2: R) -> Self {
+        /* Interval between fundamental date J2000.0 and given date (JC). */
+        Self::from_centuries(((date1 - ERFA_DJ00) + date2) / ERFA_DJC)
+    }
+
+    /// Compute the fundamental arguments for `t`, TT (strictly TDB) in Julian
+    /// centuries since J2000.0.
+    pub fn from_centuries(t: R) -> Self {
+        Self {
+            t,
+            l: l03(t),
+            lp: lp03(t),
+            f: f03(t),
+            d: d03(t),
+            om: om03(t),
+            me: me03(t),
+            ve: ve03(t),
+            e: e03(t),
+            ma: ma03(t),
+            ju: ju03(t),
+            sa: sa03(t),
+            ur: ur03(t),
+            ne: ne03(t),
+            pa: pa03(t),
+        }
+    }
+
+    /// The fundamental arguments in the order used by the IERS Conventions
+    /// tables (e.g. Table 5.2a): l, l', F, D, Om, L_Me, L_Ve, L_E, L_Ma, L_J,
+    /// L_Sa, L_U, L_Ne, p_A.
+    pub fn to_array(&self) -> [R; 14] {
+        [
+            self.l, self.lp, self.f, self.d, self.om, self.me, self.ve, self.e, self.ma, self.ju,
+            self.sa, self.ur, self.ne, self.pa,
+        ]
+    }
 }
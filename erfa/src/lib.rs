@@ -3,29 +3,45 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 //! A pure-Rust equivalent to the ERFA C library.
+//!
+//! The `std` feature is enabled by default. Without it, the crate is `no_std`,
+//! and uses `libm` for floating-point functions. The items that allocate
+//! (`Warned`, `series::Series`, `prenut::PrecessionNutationCache` and
+//! `earth::EarthEphemeris`) also need the `alloc` feature, which `std`
+//! enables; without it, no global allocator is needed.
+//!
+//! With the `reproducible` feature, `libm` is used even with `std`, so that
+//! results are bit-for-bit identical on all platforms.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(any(feature = "alloc", test))]
+extern crate alloc;
 
 pub mod aliases;
+pub mod angle;
+#[cfg(feature = "alloc")]
+mod chebyshev;
 pub mod constants;
 pub mod earth;
-pub(crate) mod ellipsoid;
+pub mod ellipsoid;
+mod error;
 pub mod fundamental_argument;
+mod math;
 pub mod misc;
+#[cfg(feature = "rayon")]
+pub mod par;
 pub mod prenut;
+pub mod real;
 pub mod separation;
+pub mod series;
 pub mod time;
 pub mod transform;
 pub mod vectors_and_matrices;
 
-pub use ellipsoid::Ellipsoid;
-
-#[derive(thiserror::Error, Debug)]
-pub enum ErfaError {
-    #[error("Function {function} indicated that value '{value}' is invalid")]
-    InvalidValue {
-        function: &'static str,
-        value: &'static str,
-    },
-
-    #[error("Function {function} indicated that it received unrealistic inputs")]
-    Unrealistic { function: &'static str },
-}
+pub use angle::{Angle, HourAngle, Latitude};
+pub use ellipsoid::{Ellipsoid, ReferenceEllipsoid};
+#[cfg(feature = "alloc")]
+pub use error::Warned;
+pub use error::{ErfaError, ErfaWarning};
+pub use vectors_and_matrices::{PVector, PvVector, RMatrix};
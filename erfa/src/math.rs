@@ -0,0 +1,146 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Floating-point functions.
+//!
+//! The crate calls the transcendental functions (and `sqrt`) through this
+//! module rather than as methods of `f64`, so that their implementation can be
+//! chosen in one place:
+//!
+//! * by default, the `std` methods are used, which may call the platform's
+//!   maths library, and so can differ in the last bit between platforms;
+//! * with the `reproducible` feature, or without `std`, the pure-Rust
+//!   implementations of `libm` are used, which give the same bits everywhere.
+//!
+//! Rust never contracts a multiply and an add into a fused multiply-add, so
+//! with `reproducible` the results do not depend on the target CPU features
+//! or the optimisation level either.
+
+#[cfg(any(feature = "reproducible", not(feature = "std")))]
+pub(crate) use libm::{atan, atan2, cos, sin, sincos as sin_cos, sqrt};
+
+#[cfg(all(feature = "std", not(feature = "reproducible")))]
+mod imp {
+    #[inline]
+    pub(crate) fn sin(x: f64) -> f64 {
+        x.sin()
+    }
+
+    #[inline]
+    pub(crate) fn cos(x: f64) -> f64 {
+        x.cos()
+    }
+
+    #[inline]
+    pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+        x.sin_cos()
+    }
+
+    #[inline]
+    pub(crate) fn atan(x: f64) -> f64 {
+        x.atan()
+    }
+
+    #[inline]
+    pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+        y.atan2(x)
+    }
+
+    #[inline]
+    pub(crate) fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+}
+#[cfg(all(feature = "std", not(feature = "reproducible")))]
+pub(crate) use imp::*;
+
+/* Without `std`, `f64` has no `floor`, `ceil` or `trunc` methods; `libm`
+provides them. These are exact, so they need no switching. */
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+pub(crate) use libm::ceil;
+#[cfg(not(feature = "std"))]
+pub(crate) use libm::{floor, trunc};
+
+#[cfg(feature = "std")]
+mod exact {
+    #[inline]
+    pub(crate) fn floor(x: f64) -> f64 {
+        x.floor()
+    }
+
+    #[inline]
+    pub(crate) fn ceil(x: f64) -> f64 {
+        x.ceil()
+    }
+
+    #[inline]
+    pub(crate) fn trunc(x: f64) -> f64 {
+        x.trunc()
+    }
+}
+#[cfg(feature = "std")]
+pub(crate) use exact::*;
+
+#[cfg(all(test, feature = "reproducible"))]
+mod tests {
+    use crate::{earth::position_velocity_00, prenut::nut00a, time::gst06a};
+
+    /// The dates of the pinned results, as 2-part Julian Dates.
+    const DATES: [(f64, f64); 2] = [(2400000.5, 53736.0), (2451545.0, 0.0)];
+
+    fn bits(m: [[f64; 3]; 2]) -> [[u64; 3]; 2] {
+        m.map(|v| v.map(f64::to_bits))
+    }
+
+    #[test]
+    fn test_reproducible_gst06a() {
+        let expected = [0x3ffc111083110986, 0x401394607cf11d75];
+        for ((d1, d2), expected) in DATES.into_iter().zip(expected) {
+            assert_eq!(gst06a(d1, d2, d1, d2).to_bits(), expected);
+        }
+    }
+
+    #[test]
+    fn test_reproducible_nut00a() {
+        let expected = [
+            (0xbee4328e119417ee, 0x3f054d965975c173),
+            (0xbf11b4d0efdeca27, 0xbefd545ce227381e),
+        ];
+        for ((d1, d2), expected) in DATES.into_iter().zip(expected) {
+            let (dpsi, deps) = nut00a(d1, d2);
+            assert_eq!((dpsi.to_bits(), deps.to_bits()), expected);
+        }
+    }
+
+    #[test]
+    fn test_reproducible_position_velocity_00() {
+        let expected = [
+            (
+                [
+                    [0xbfc6994848d990f0, 0x3fec66d57381f311, 0x3fd8a066c188c70e],
+                    [0xbf919ffde634daf4, 0xbf67b7beabe15316, 0xbf5491669beaebb6],
+                ],
+                [
+                    [0xbfc61a2e9dd16251, 0x3fec77c7899c069f, 0x3fd8acff1def3946],
+                    [0xbf91a0b2830908f4, 0xbf67aba06332993e, 0xbf5486e71babdcbb],
+                ],
+            ),
+            (
+                [
+                    [0xbfc6ac5cb05a8c19, 0x3fec65d0812c5d80, 0x3fd89fa0a367998d],
+                    [0xbf919ee02548de53, 0xbf67bde604673459, 0xbf5495b383934706],
+                ],
+                [
+                    [0xbfc79635a7b74fae, 0x3fec5021504c2dcc, 0x3fd890817a5874ba],
+                    [0xbf919d77353a75f5, 0xbf67cc123d20998e, 0xbf54a26c0375b303],
+                ],
+            ),
+        ];
+        for ((d1, d2), (pvh, pvb)) in DATES.into_iter().zip(expected) {
+            let pv = position_velocity_00(d1, d2);
+            assert_eq!(bits(pv.pvh), pvh);
+            assert_eq!(bits(pv.pvb), pvb);
+        }
+    }
+}
@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::constants::ERFA_D2PI;
+use crate::{constants::ERFA_D2PI, real::Real};
 
 /// Normalize angle into the range `0 <= a < 2pi`. (`eraAnp`)
 ///
@@ -12,10 +12,10 @@ use crate::constants::ERFA_D2PI;
 /// Returned:
 /// * angle in range `0-2pi`
 ///
-pub fn norm_angle(a: f64) -> f64 {
+pub fn norm_angle<R: Real>(a: R) -> R {
     let mut w = a % ERFA_D2PI;
-    if w < 0.0 {
-        w += ERFA_D2PI;
+    if w < R::from_f64(0.0) {
+        w = w + ERFA_D2PI;
     }
 
     w
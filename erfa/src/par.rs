@@ -0,0 +1,271 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Parallel (`rayon`) versions of the more expensive functions, for large
+//! catalogues and long time series. Requires the `rayon` feature.
+//!
+//! Each element is computed by the corresponding serial function, so results
+//! are bit-for-bit identical to the serial versions, in the same order,
+//! regardless of the number of threads.
+//!
+//! # Panics
+//!
+//! All of these functions panic if the input and output slices don't have the
+//! same length.
+
+use rayon::prelude::*;
+
+use crate::{
+    earth::{self, EarthPv},
+    prenut, separation, time,
+    transform::{
+        self,
+        batch::{check_lengths, SiteLatitude},
+    },
+    ErfaError, ReferenceEllipsoid,
+};
+
+/// Nutation, IAU 2000A model, for many TT dates. See
+/// [`nut00a`](prenut::nut00a).
+pub fn par_nut00a(date1: &[f64], date2: &[f64], dpsi: &mut [f64], deps: &mut [f64]) {
+    check_lengths(
+        "par_nut00a",
+        date1.len(),
+        &[date2.len(), dpsi.len(), deps.len()],
+    );
+    (date1, date2, dpsi, deps)
+        .into_par_iter()
+        .for_each(|(&date1, &date2, dpsi, deps)| (*dpsi, *deps) = prenut::nut00a(date1, date2));
+}
+
+/// Precession-nutation matrices, IAU 2006/2000A, for many TT dates. See
+/// [`pn_matrix_06a`](prenut::pn_matrix_06a).
+pub fn par_pn_matrix_06a(date1: &[f64], date2: &[f64], rbpn: &mut [[[f64; 3]; 3]]) {
+    check_lengths("par_pn_matrix_06a", date1.len(), &[date2.len(), rbpn.len()]);
+    (date1, date2, rbpn)
+        .into_par_iter()
+        .for_each(|(&date1, &date2, rbpn)| *rbpn = prenut::pn_matrix_06a(date1, date2));
+}
+
+/// Greenwich apparent sidereal time, IAU 2006/2000A, for many dates. See
+/// [`gst06a`](time::gst06a).
+pub fn par_gst06a(uta: &[f64], utb: &[f64], tta: &[f64], ttb: &[f64], gst: &mut [f64]) {
+    check_lengths(
+        "par_gst06a",
+        uta.len(),
+        &[utb.len(), tta.len(), ttb.len(), gst.len()],
+    );
+    (uta, utb, tta, ttb, gst)
+        .into_par_iter()
+        .for_each(|(&uta, &utb, &tta, &ttb, gst)| *gst = time::gst06a(uta, utb, tta, ttb));
+}
+
+/// Earth position and velocity for many TDB dates. See
+/// [`position_velocity_00`](earth::position_velocity_00).
+pub fn par_position_velocity_00(date1: &[f64], date2: &[f64]) -> Vec<EarthPv> {
+    check_lengths("par_position_velocity_00", date1.len(), &[date2.len()]);
+    (date1, date2)
+        .into_par_iter()
+        .map(|(&date1, &date2)| earth::position_velocity_00(date1, date2))
+        .collect()
+}
+
+/// Equatorial to horizon coordinates for many directions seen from one site.
+/// See [`batch::hadec_to_azel`](transform::batch::hadec_to_azel).
+pub fn par_hadec_to_azel<P: Into<SiteLatitude>>(
+    ha: &[f64],
+    dec: &[f64],
+    phi: P,
+    az: &mut [f64],
+    el: &mut [f64],
+) {
+    check_lengths(
+        "par_hadec_to_azel",
+        ha.len(),
+        &[dec.len(), az.len(), el.len()],
+    );
+    let phi = phi.into();
+    (ha, dec, az, el)
+        .into_par_iter()
+        .for_each(|(&ha, &dec, az, el)| (*az, *el) = phi.hadec_to_azel(ha, dec));
+}
+
+/// Horizon to equatorial coordinates for many directions seen from one site.
+/// See [`batch::azel_to_hadec`](transform::batch::azel_to_hadec).
+pub fn par_azel_to_hadec<P: Into<SiteLatitude>>(
+    az: &[f64],
+    el: &[f64],
+    phi: P,
+    ha: &mut [f64],
+    dec: &mut [f64],
+) {
+    check_lengths(
+        "par_azel_to_hadec",
+        az.len(),
+        &[el.len(), ha.len(), dec.len()],
+    );
+    let phi = phi.into();
+    (az, el, ha, dec)
+        .into_par_iter()
+        .for_each(|(&az, &el, ha, dec)| (*ha, *dec) = phi.azel_to_hadec(az, el));
+}
+
+/// Parallactic angles for many directions seen from one site. See
+/// [`batch::hadec_to_parallactic_angle`](transform::batch::hadec_to_parallactic_angle).
+pub fn par_hadec_to_parallactic_angle<P: Into<SiteLatitude>>(
+    ha: &[f64],
+    dec: &[f64],
+    phi: P,
+    pa: &mut [f64],
+) {
+    check_lengths(
+        "par_hadec_to_parallactic_angle",
+        ha.len(),
+        &[dec.len(), pa.len()],
+    );
+    let phi = phi.into();
+    (ha, dec, pa)
+        .into_par_iter()
+        .for_each(|(&ha, &dec, pa)| *pa = phi.hadec_to_parallactic_angle(ha, dec));
+}
+
+/// Transform many geodetic coordinates to geocentric vectors. See
+/// [`batch::geodetic_to_geocentric`](transform::batch::geodetic_to_geocentric).
+///
+/// # Errors
+///
+/// This function will return an error if any element is unrealistic; unlike
+/// the serial version, which outputs have been written is unspecified.
+pub fn par_geodetic_to_geocentric<E: Into<ReferenceEllipsoid>>(
+    e: E,
+    elong: &[f64],
+    phi: &[f64],
+    height: &[f64],
+    xyz: &mut [[f64; 3]],
+) -> Result<(), ErfaError> {
+    check_lengths(
+        "par_geodetic_to_geocentric",
+        elong.len(),
+        &[phi.len(), height.len(), xyz.len()],
+    );
+    let e = e.into();
+    (elong, phi, height, xyz)
+        .into_par_iter()
+        .try_for_each(|(&elong, &phi, &height, xyz)| {
+            *xyz = transform::geodetic_to_geocentric(e, elong, phi, height)?;
+            Ok(())
+        })
+}
+
+/// Transform many geocentric vectors to geodetic coordinates. See
+/// [`batch::geocentric_to_geodetic`](transform::batch::geocentric_to_geodetic).
+///
+/// # Errors
+///
+/// As for [`geocentric_to_geodetic`](transform::geocentric_to_geodetic).
+pub fn par_geocentric_to_geodetic<E: Into<ReferenceEllipsoid>>(
+    e: E,
+    xyz: &[[f64; 3]],
+    geodetic: &mut [[f64; 3]],
+) -> Result<(), ErfaError> {
+    check_lengths("par_geocentric_to_geodetic", xyz.len(), &[geodetic.len()]);
+    let e = e.into();
+    (xyz, geodetic)
+        .into_par_iter()
+        .try_for_each(|(&xyz, geodetic)| {
+            *geodetic = transform::geocentric_to_geodetic(e, xyz)?;
+            Ok(())
+        })
+}
+
+/// Angular separations of many points from the point (`a_long`, `a_lat`). See
+/// [`batch::sep_spherical_coords`](separation::batch::sep_spherical_coords).
+pub fn par_sep_spherical_coords(
+    a_long: f64,
+    a_lat: f64,
+    b_long: &[f64],
+    b_lat: &[f64],
+    sep: &mut [f64],
+) {
+    check_lengths(
+        "par_sep_spherical_coords",
+        b_long.len(),
+        &[b_lat.len(), sep.len()],
+    );
+    let ac = transform::spherical_to_cartesian(a_long, a_lat);
+    (b_long, b_lat, sep)
+        .into_par_iter()
+        .for_each(|(&b_long, &b_lat, sep)| {
+            *sep = separation::sep_vectors(ac, transform::spherical_to_cartesian(b_long, b_lat))
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dates() -> (Vec<f64>, Vec<f64>) {
+        let n = 100;
+        let date1 = vec![2400000.5; n];
+        let date2 = (0..n).map(|i| 50000.0 + 37.3 * i as f64).collect();
+        (date1, date2)
+    }
+
+    #[test]
+    fn test_par_time_series_match_serial() {
+        let (date1, date2) = dates();
+        let n = date1.len();
+
+        let (mut dpsi, mut deps) = (vec![0.0; n], vec![0.0; n]);
+        par_nut00a(&date1, &date2, &mut dpsi, &mut deps);
+        let mut rbpn = vec![[[0.0; 3]; 3]; n];
+        par_pn_matrix_06a(&date1, &date2, &mut rbpn);
+        let mut gst = vec![0.0; n];
+        par_gst06a(&date1, &date2, &date1, &date2, &mut gst);
+        let pv = par_position_velocity_00(&date1, &date2);
+
+        for i in 0..n {
+            assert_eq!((dpsi[i], deps[i]), prenut::nut00a(date1[i], date2[i]));
+            assert_eq!(rbpn[i], prenut::pn_matrix_06a(date1[i], date2[i]));
+            assert_eq!(gst[i], time::gst06a(date1[i], date2[i], date1[i], date2[i]));
+            assert_eq!(pv[i], earth::position_velocity_00(date1[i], date2[i]));
+        }
+    }
+
+    #[test]
+    fn test_par_catalogue_matches_serial() {
+        let n = 1000;
+        let ha: Vec<_> = (0..n).map(|i| -3.1 + 6.2 * i as f64 / n as f64).collect();
+        let dec: Vec<_> = (0..n)
+            .map(|i| -1.5 + 3.0 * (i * 7 % n) as f64 / n as f64)
+            .collect();
+        let phi = -0.4660608339;
+
+        let (mut az, mut el) = (vec![0.0; n], vec![0.0; n]);
+        par_hadec_to_azel(&ha, &dec, phi, &mut az, &mut el);
+        let (mut az2, mut el2) = (vec![0.0; n], vec![0.0; n]);
+        transform::batch::hadec_to_azel(&ha, &dec, phi, &mut az2, &mut el2);
+        assert_eq!((az, el), (az2, el2));
+
+        let mut sep = vec![0.0; n];
+        par_sep_spherical_coords(1.0, 0.1, &ha, &dec, &mut sep);
+        let mut sep2 = vec![0.0; n];
+        separation::batch::sep_spherical_coords(1.0, 0.1, &ha, &dec, &mut sep2);
+        assert_eq!(sep, sep2);
+
+        let height = vec![377.827; n];
+        let mut xyz = vec![[0.0; 3]; n];
+        par_geodetic_to_geocentric(crate::Ellipsoid::WGS84, &ha, &dec, &height, &mut xyz).unwrap();
+        let mut xyz2 = vec![[0.0; 3]; n];
+        transform::batch::geodetic_to_geocentric(
+            crate::Ellipsoid::WGS84,
+            &ha,
+            &dec,
+            &height,
+            &mut xyz2,
+        )
+        .unwrap();
+        assert_eq!(xyz, xyz2);
+    }
+}
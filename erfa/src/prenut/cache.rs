@@ -0,0 +1,241 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! An interpolating cache of the IAU 2006/2000A precession-nutation over a
+//! range of TT dates.
+
+use alloc::vec::Vec;
+
+use super::{bpn_to_xy, eors, fw_to_matrix, nut06a_with_args, precession_angles_fw06_t};
+use crate::{
+    chebyshev, fundamental_argument::FundamentalArguments, math, time::S06_with_args, ErfaError,
+};
+
+/// The longest interval (days) covered by one set of Chebyshev coefficients.
+const MAX_SEGMENT_DAYS: f64 = 1.0;
+
+/// The longest interval (days) a cache may cover: about 270 years, and 40 MB
+/// of coefficients.
+const MAX_DAYS: f64 = 100_000.0;
+
+/// The number of Chebyshev coefficients per quantity and segment.
+const N_COEFFS: usize = 10;
+
+/// The precession-nutation quantities returned by
+/// [`PrecessionNutationCache::get`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CipQuantities {
+    /// Bias-precession-nutation matrix (GCRS to true equator and equinox of
+    /// date), as from [`pn_matrix_06a`](super::pn_matrix_06a)
+    pub rnpb: [[f64; 3]; 3],
+    /// Celestial Intermediate Pole X coordinate
+    pub x: f64,
+    /// Celestial Intermediate Pole Y coordinate
+    pub y: f64,
+    /// The CIO locator s (radians)
+    pub s: f64,
+    /// The equation of the origins (radians)
+    pub eo: f64,
+}
+
+/// Interpolating cache for the IAU 2006/2000A precession-nutation quantities
+/// over an interval of TT.
+///
+/// Evaluating the full IAU 2000A nutation series is expensive; when the
+/// precession-nutation is needed at many times within a short span (e.g. every
+/// integration of an observation), it is much cheaper to evaluate the series
+/// at a few times and interpolate. This cache fits Chebyshev polynomials to the
+/// Fukushima-Williams angles (bias, precession and nutation) and to the CIO
+/// locator s over segments of at most a day, and rebuilds the requested
+/// quantities from them.
+///
+/// # Notes:
+///
+/// 1) The interpolation error is below 1 microarcsecond in all the returned
+///    angles (X, Y, s, EO and the matrix elements); in practice it is a few
+///    orders of magnitude smaller. This is far below the accuracy of the
+///    underlying models.
+///
+/// 2) The matrix is rebuilt from the interpolated Fukushima-Williams angles
+///    with [`fw_to_matrix`](super::fw_to_matrix), so it is always a rotation
+///    matrix. X and Y are extracted from it with
+///    [`bpn_to_xy`](super::bpn_to_xy) and EO is computed from it and s with
+///    [`eors`](super::eors), so the quantities are mutually consistent.
+///
+/// 3) Dates are handled as 2-part Julian Dates throughout, so that no
+///    precision is lost to the size of the Julian Date.
+///
+#[derive(Debug, Clone)]
+pub struct PrecessionNutationCache {
+    /// Start of the interval, TT as a 2-part Julian Date.
+    date1: f64,
+    date2: f64,
+    /// Length of the interval (days).
+    days: f64,
+    /// Length of each segment (days).
+    segment_days: f64,
+    /// Chebyshev coefficients for gamb, phib, psi, eps and s, per segment.
+    segments: Vec<[[f64; N_COEFFS]; 5]>,
+}
+
+impl PrecessionNutationCache {
+    /// Build a cache covering the TT interval from `date1+date2` to
+    /// `date1+date2+days`.
+    ///
+    /// Given:
+    ///  * `date1`,`date2`: start of the interval, TT as a 2-part Julian Date
+    ///  * `days`: length of the interval (days, > 0, at most 100,000)
+    ///
+    /// The cost of building the cache is that of about 10 calls to
+    /// [`pn_matrix_06a`](super::pn_matrix_06a) per day of the interval (at
+    /// least 10 in total).
+    pub fn new(date1: f64, date2: f64, days: f64) -> Result<Self, ErfaError> {
+        if !(days.is_finite() && days > 0.0 && days <= MAX_DAYS && (date1 + date2).is_finite()) {
+            return Err(ErfaError::InvalidValue {
+                function: "PrecessionNutationCache::new",
+                value: "days",
+            });
+        }
+
+        let n_segments = math::ceil(days / MAX_SEGMENT_DAYS).max(1.0) as usize;
+        let segment_days = days / n_segments as f64;
+        let nodes: Vec<f64> = chebyshev::nodes(N_COEFFS).collect();
+
+        let segments = (0..n_segments)
+            .map(|i| {
+                /* Evaluate the angles at the nodes of this segment. */
+                let mut values = [[0.0; N_COEFFS]; 5];
+                for (k, node) in nodes.iter().enumerate() {
+                    let offset = (i as f64 + 0.5 * (node + 1.0)) * segment_days;
+                    let fa = FundamentalArguments::new(date1, date2 + offset);
+                    let fw = precession_angles_fw06_t(fa.t);
+                    let (dp, de) = nut06a_with_args(&fa);
+                    let psi = fw.psib + dp;
+                    let eps = fw.epsa + de;
+                    let (x, y) = bpn_to_xy(fw_to_matrix(fw.gamb, fw.phib, psi, eps));
+                    let s = S06_with_args(&fa, x, y);
+                    for (v, q) in values.iter_mut().zip([fw.gamb, fw.phib, psi, eps, s]) {
+                        v[k] = q;
+                    }
+                }
+
+                /* Fit them. */
+                values.map(|v| {
+                    let mut c = [0.0; N_COEFFS];
+                    c.copy_from_slice(&chebyshev::fit(&v));
+                    c
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            date1,
+            date2,
+            days,
+            segment_days,
+            segments,
+        })
+    }
+
+    /// The start of the cached interval, TT as a 2-part Julian Date.
+    pub fn start(&self) -> (f64, f64) {
+        (self.date1, self.date2)
+    }
+
+    /// The length of the cached interval (days).
+    pub fn days(&self) -> f64 {
+        self.days
+    }
+
+    /// The precession-nutation quantities at TT `date1+date2`, which must lie
+    /// within the cached interval.
+    ///
+    /// Given:
+    ///  * `date1`,`date2`: TT as a 2-part Julian Date
+    ///
+    /// Returned (as a [`CipQuantities`]):
+    ///  * `rnpb`: bias-precession-nutation matrix
+    ///  * `x`,`y`: Celestial Intermediate Pole
+    ///  * `s`: the CIO locator s (radians)
+    ///  * `eo`: equation of the origins (radians)
+    ///
+    /// An [`ErfaError::InvalidDate`] is returned if the date is outside the
+    /// cached interval.
+    pub fn get(&self, date1: f64, date2: f64) -> Result<CipQuantities, ErfaError> {
+        /* Offset from the start of the interval, keeping precision. */
+        let dt = (date1 - self.date1) + (date2 - self.date2);
+        if !(0.0..=self.days).contains(&dt) {
+            return Err(ErfaError::InvalidDate {
+                function: "PrecessionNutationCache::get",
+            });
+        }
+
+        /* Segment and position within it, scaled to [-1, 1]. */
+        let (i, u) = chebyshev::locate(dt, self.segment_days, self.segments.len());
+        let [gamb, phib, psi, eps, s] = self.segments[i].map(|c| chebyshev::eval(&c, u));
+
+        let rnpb = fw_to_matrix(gamb, phib, psi, eps);
+        let (x, y) = bpn_to_xy(rnpb);
+        let eo = eors(rnpb, s);
+        Ok(CipQuantities { rnpb, x, y, s, eo })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{constants::ERFA_DAS2R, prenut::pn_matrix_06a, time::S06};
+
+    /// One microarcsecond, in radians.
+    const UAS: f64 = ERFA_DAS2R * 1e-6;
+
+    fn check(cache: &PrecessionNutationCache, date1: f64, date2: f64) {
+        let q = cache.get(date1, date2).unwrap();
+        let rnpb = pn_matrix_06a(date1, date2);
+        let (x, y) = bpn_to_xy(rnpb);
+        let s = S06(date1, date2, x, y);
+        let eo = eors(rnpb, s);
+        for (q_row, row) in q.rnpb.iter().zip(rnpb) {
+            for (q_el, el) in q_row.iter().zip(row) {
+                assert!((q_el - el).abs() < UAS, "{q_el} {el}");
+            }
+        }
+        assert!((q.x - x).abs() < UAS, "{} {}", q.x, x);
+        assert!((q.y - y).abs() < UAS, "{} {}", q.y, y);
+        assert!((q.s - s).abs() < UAS, "{} {}", q.s, s);
+        assert!((q.eo - eo).abs() < UAS, "{} {}", q.eo, eo);
+    }
+
+    #[test]
+    fn test_cache_short_interval() {
+        /* Two minutes, every half second. */
+        let cache = PrecessionNutationCache::new(2460000.5, 0.123, 120.0 / 86400.0).unwrap();
+        for i in 0..=240 {
+            check(&cache, 2460000.5, 0.123 + i as f64 * 0.5 / 86400.0);
+        }
+    }
+
+    #[test]
+    fn test_cache_long_interval() {
+        /* Ten days, every 17 minutes. */
+        let cache = PrecessionNutationCache::new(2400000.5, 53736.0, 10.0).unwrap();
+        for i in 0..=(10 * 1440 / 17) {
+            check(&cache, 2400000.5, 53736.0 + i as f64 * 17.0 / 1440.0);
+        }
+    }
+
+    #[test]
+    fn test_cache_bounds() {
+        assert!(PrecessionNutationCache::new(2451545.0, 0.0, 0.0).is_err());
+        assert!(PrecessionNutationCache::new(2451545.0, 0.0, f64::NAN).is_err());
+        assert!(PrecessionNutationCache::new(2451545.0, 0.0, 1e20).is_err());
+        let cache = PrecessionNutationCache::new(2451545.0, 0.0, 2.5).unwrap();
+        assert_eq!(cache.start(), (2451545.0, 0.0));
+        assert_eq!(cache.days(), 2.5);
+        assert!(cache.get(2451545.0, 0.0).is_ok());
+        assert!(cache.get(2451547.0, 0.5).is_ok());
+        assert!(cache.get(2451545.0, -1e-6).is_err());
+        assert!(cache.get(2451547.5, 1e-6).is_err());
+    }
+}
@@ -4,10 +4,21 @@
 
 //! Precession and nutation code.
 
+#[cfg(feature = "alloc")]
+mod cache;
 mod nut00a;
-pub use nut00a::nut00a;
-
-use crate::constants::*;
+#[cfg(feature = "wide")]
+pub mod simd;
+pub mod typed;
+#[cfg(feature = "alloc")]
+pub use cache::{CipQuantities, PrecessionNutationCache};
+pub use nut00a::{nut00a, nut00a_fast, nut00a_with_args};
+
+use crate::{
+    constants::*,
+    fundamental_argument::FundamentalArguments,
+    real::{polynomial, Real},
+};
 
 /// Mean obliquity of the ecliptic, IAU 2006 precession model. (`eraObl06`)
 ///
@@ -44,27 +55,85 @@ use crate::constants::*;
 ///
 /// * Hilton, J. et al., 2006, Celest.Mech.Dyn.Astron. 94, 351
 ///
-pub fn obliquity_06(date1: f64, date2: f64) -> f64 {
+pub fn obliquity_06<R: Real>(date1: R, date2: R) -> R {
     /* Interval between fundamental date J2000.0 and given date (JC). */
-    let t = ((date1 - ERFA_DJ00) + date2) / ERFA_DJC;
+    obliquity_06_t(((date1 - ERFA_DJ00) + date2) / ERFA_DJC)
+}
 
+/// [`obliquity_06`] given `t`, TT in Julian centuries since J2000.0.
+fn obliquity_06_t<R: Real>(t: R) -> R {
     /* Mean obliquity. */
     #[rustfmt::skip]
-   let eps0 = (84381.406     +
-                   (-46.836769    +
-                   ( -0.0001831   +
-                   (  0.00200340  +
-                   ( -0.000000576 +
-                   ( -0.0000000434) * t) * t) * t) * t) * t) * ERFA_DAS2R;
+    let eps0 = polynomial(t, &[
+        84381.406,
+          -46.836769,
+           -0.0001831,
+            0.00200340,
+           -0.000000576,
+           -0.0000000434,
+    ]) * ERFA_DAS2R;
     eps0
 }
 
+/// Precession angles, IAU 2006, equinox based, as returned by
+/// [`precession_angles`]. All values are in radians; see Note 2 of that
+/// function for their meanings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrecessionAngles06 {
+    /// `epsilon_0`: obliquity at J2000.0
+    pub eps0: f64,
+    /// `psi_A`: luni-solar precession
+    pub psia: f64,
+    /// `omega_A`: inclination of equator wrt J2000.0 ecliptic
+    pub oma: f64,
+    /// `P_A`: ecliptic pole x, J2000.0 ecliptic triad
+    pub bpa: f64,
+    /// `Q_A`: ecliptic pole -y, J2000.0 ecliptic triad
+    pub bqa: f64,
+    /// `pi_A`: angle between moving and J2000.0 ecliptics
+    pub pia: f64,
+    /// `Pi_A`: longitude of ascending node of the ecliptic
+    pub bpia: f64,
+    /// `epsilon_A`: obliquity of the ecliptic
+    pub epsa: f64,
+    /// `chi_A`: planetary precession
+    pub chia: f64,
+    /// `z_A`: equatorial precession: -3rd 323 Euler angle
+    pub za: f64,
+    /// `zeta_A`: equatorial precession: -1st 323 Euler angle
+    pub zetaa: f64,
+    /// `theta_A`: equatorial precession: 2nd 323 Euler angle
+    pub thetaa: f64,
+    /// `p_A`: general precession
+    pub pa: f64,
+    /// `gamma_J2000`: J2000.0 RA difference of ecliptic poles
+    pub gam: f64,
+    /// `phi_J2000`: J2000.0 codeclination of ecliptic pole
+    pub phi: f64,
+    /// `psi_J2000`: longitude difference of equator poles, J2000.0
+    pub psi: f64,
+}
+
+/// Fukushima-Williams precession angles, IAU 2006, as returned by
+/// [`precession_angles_fw06`]. All values are in radians.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrecessionAnglesFw06<R = f64> {
+    /// F-W angle gamma_bar
+    pub gamb: R,
+    /// F-W angle phi_bar
+    pub phib: R,
+    /// F-W angle psi_bar
+    pub psib: R,
+    /// F-W angle epsilon_A
+    pub epsa: R,
+}
+
 /// Precession angles, IAU 2006, equinox based. (`eraP06e`)
 ///
 /// Given:
 ///  * `date1`,`date2`: TT as a 2-part Julian Date (Note 1)
 ///
-/// Returned (see Note 2):
+/// Returned (as a [`PrecessionAngles06`], see Note 2):
 ///  * `eps0`: epsilon_0
 ///  * `psia`: psi_A
 ///  * `oma`: omega_A
@@ -160,9 +229,6 @@ pub fn obliquity_06(date1: f64, date2: f64) -> f64 {
 ///    rather than the dynamical pole, it may (depending on the choice of
 ///    angles) be necessary to introduce the frame bias explicitly.
 ///
-/// 8) It is permissible to re-use the same variable in the returned arguments.
-///    The quantities are stored in the stated order.
-///
 /// References:
 ///
 /// * Capitaine, N., Wallace, P.T. & Chapront, J., 2003, Astron.Astrophys., 412,
@@ -170,28 +236,7 @@ pub fn obliquity_06(date1: f64, date2: f64) -> f64 {
 ///
 /// * Hilton, J. et al., 2006, Celest.Mech.Dyn.Astron. 94, 351
 ///
-#[allow(clippy::type_complexity)]
-pub fn precession_angles(
-    date1: f64,
-    date2: f64,
-) -> (
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-    f64,
-) {
+pub fn precession_angles(date1: f64, date2: f64) -> PrecessionAngles06 {
     /* Interval between fundamental date J2000.0 and given date (JC). */
     let t = ((date1 - ERFA_DJ00) + date2) / ERFA_DJC;
 
@@ -328,9 +373,24 @@ pub fn precession_angles(
               (   -0.0000000148 )
               * t) * t) * t) * t) * t * ERFA_DAS2R;
 
-    (
-        eps0, psia, oma, bpa, bqa, pia, bpia, epsa, chia, za, zetaa, thetaa, pa, gam, phi, psi,
-    )
+    PrecessionAngles06 {
+        eps0,
+        psia,
+        oma,
+        bpa,
+        bqa,
+        pia,
+        bpia,
+        epsa,
+        chia,
+        za,
+        zetaa,
+        thetaa,
+        pa,
+        gam,
+        phi,
+        psi,
+    }
 }
 
 /// Precession angles, IAU 2006 (Fukushima-Williams 4-angle formulation).
@@ -339,7 +399,7 @@ pub fn precession_angles(
 /// Given:
 ///  * `date1`,`date2`: TT as a 2-part Julian Date (Note 1)
 ///
-/// Returned:
+/// Returned (as a [`PrecessionAnglesFw06`]):
 ///  * `gamb`: F-W angle gamma_bar (radians)
 ///  * `phib`: F-W angle phi_bar (radians)
 ///  * `psib`: F-W angle psi_bar (radians)
@@ -396,38 +456,49 @@ pub fn precession_angles(
 ///
 /// * Hilton, J. et al., 2006, Celest.Mech.Dyn.Astron. 94, 351
 ///
-pub fn precession_angles_fw06(date1: f64, date2: f64) -> (f64, f64, f64, f64) {
+pub fn precession_angles_fw06<R: Real>(date1: R, date2: R) -> PrecessionAnglesFw06<R> {
     /* Interval between fundamental date J2000.0 and given date (JC). */
-    let t = ((date1 - ERFA_DJ00) + date2) / ERFA_DJC;
+    precession_angles_fw06_t(((date1 - ERFA_DJ00) + date2) / ERFA_DJC)
+}
 
+/// [`precession_angles_fw06`] given `t`, TT in Julian centuries since J2000.0.
+fn precession_angles_fw06_t<R: Real>(t: R) -> PrecessionAnglesFw06<R> {
     /* P03 bias+precession angles. */
     #[rustfmt::skip]
-    let gamb = (    -0.052928     +
-                    (    10.556378     +
-                    (     0.4932044    +
-                    (    -0.00031238   +
-                    (    -0.000002788  +
-                    (     0.0000000260 )
-                    * t) * t) * t) * t) * t) * ERFA_DAS2R;
+    let gamb = polynomial(t, &[
+           -0.052928,
+           10.556378,
+            0.4932044,
+           -0.00031238,
+           -0.000002788,
+            0.0000000260,
+    ]) * ERFA_DAS2R;
     #[rustfmt::skip]
-    let phib = ( 84381.412819     +
-                    (   -46.811016     +
-                    (     0.0511268    +
-                    (     0.00053289   +
-                    (    -0.000000440  +
-                    (    -0.0000000176 )
-                    * t) * t) * t) * t) * t) * ERFA_DAS2R;
+    let phib = polynomial(t, &[
+        84381.412819,
+          -46.811016,
+            0.0511268,
+            0.00053289,
+           -0.000000440,
+           -0.0000000176,
+    ]) * ERFA_DAS2R;
     #[rustfmt::skip]
-    let psib = (    -0.041775     +
-                    (  5038.481484     +
-                    (     1.5584175    +
-                    (    -0.00018522   +
-                    (    -0.000026452  +
-                    (    -0.0000000148 )
-                    * t) * t) * t) * t) * t) * ERFA_DAS2R;
-    let epsa = obliquity_06(date1, date2);
-
-    (gamb, phib, psib, epsa)
+    let psib = polynomial(t, &[
+           -0.041775,
+         5038.481484,
+            1.5584175,
+           -0.00018522,
+           -0.000026452,
+           -0.0000000148,
+    ]) * ERFA_DAS2R;
+    let epsa = obliquity_06_t(t);
+
+    PrecessionAnglesFw06 {
+        gamb,
+        phib,
+        psib,
+        epsa,
+    }
 }
 
 /// IAU 2000A nutation with adjustments to match the IAU 2006 precession.
@@ -489,23 +560,91 @@ pub fn precession_angles_fw06(date1: f64, date2: f64) -> (f64, f64, f64, f64) {
 /// * Wallace, P.T., "Software for Implementing the IAU 2000 Resolutions", in
 ///   IERS Workshop 5.1 (2002)
 ///
-pub fn nut06a(date1: f64, date2: f64) -> (f64, f64) {
+pub fn nut06a<R: Real>(date1: R, date2: R) -> (R, R) {
+    nut06a_with_args(&FundamentalArguments::new(date1, date2))
+}
+
+/// IAU 2000A nutation with adjustments to match the IAU 2006 precession, given
+/// precomputed fundamental arguments. See [`nut06a`]; the results are
+/// identical.
+pub fn nut06a_with_args<R: Real>(fa: &FundamentalArguments<R>) -> (R, R) {
     /* Interval between fundamental date J2000.0 and given date (JC). */
-    let t = ((date1 - ERFA_DJ00) + date2) / ERFA_DJC;
+    let t = fa.t;
 
     /* Factor correcting for secular variation of J2. */
-    let fj2 = -2.7774e-6 * t;
+    let fj2 = t * -2.7774e-6;
 
     /* Obtain IAU 2000A nutation. */
-    let (dp, de) = nut00a(date1, date2);
+    let (dp, de) = nut00a_with_args(fa);
 
     /* Apply P03 adjustments (Wallace & Capitaine, 2006, Eqs.5). */
-    let dpsi = dp + dp * (0.4697e-6 + fj2);
+    let dpsi = dp + dp * (fj2 + 0.4697e-6);
     let deps = de + de * fj2;
 
     (dpsi, deps)
 }
 
+/// How much of the IAU 2000A nutation series to evaluate, for
+/// [`nut06a_with_accuracy`] and the functions built on it.
+///
+/// The errors quoted for the reduced levels are the largest differences from
+/// the full model, rounded up, found by sampling every 6 hours over 1900-2100
+/// AD and then every minute around the largest samples. The speedups are for
+/// the nutation alone (the sidereal time also needs the CIO locator and the
+/// matrix, so gains less).
+///
+/// | Level       | `dpsi`  | `deps`  | GST     | Speedup |
+/// |-------------|---------|---------|---------|---------|
+/// | `Full`      | 0       | 0       | 0       | 1       |
+/// | `Truncated` | 3.1 mas | 1.2 mas | 2.8 mas | ~20     |
+/// | `Low`       | 0.9 mas | 0.6 mas | 0.8 mas | ~9      |
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NutationAccuracy {
+    /// The full IAU 2000A model: 678 luni-solar and 687 planetary terms. The
+    /// results are identical to [`nut06a`].
+    #[default]
+    Full,
+    /// The 77 largest luni-solar terms and a fixed offset for the planetary
+    /// terms, as in the IAU 2000B model. The pole is good to about 1 mas.
+    Truncated,
+    /// The 150 largest luni-solar terms and the 20 largest planetary terms,
+    /// for nutation and sidereal time good to about 1 mas. This is slower
+    /// than `Truncated`, but more accurate.
+    Low,
+}
+
+/// Nutation, IAU 2006/2000A, evaluated to a chosen [`NutationAccuracy`].
+///
+/// Given:
+///  * `date1`,`date2`: TT as a 2-part Julian Date
+///  * `accuracy`: how much of the series to evaluate
+///
+/// Returned:
+///  * `dpsi`,`deps`: nutation (luni-solar + planetary, radians)
+///
+/// With [`NutationAccuracy::Full`], this is [`nut06a`]; see
+/// [`NutationAccuracy`] for the errors of the reduced levels.
+pub fn nut06a_with_accuracy(date1: f64, date2: f64, accuracy: NutationAccuracy) -> (f64, f64) {
+    nut06a_with_args_accuracy(&FundamentalArguments::new(date1, date2), accuracy)
+}
+
+/// [`nut06a_with_accuracy`] given precomputed fundamental arguments.
+pub(crate) fn nut06a_with_args_accuracy(
+    fa: &FundamentalArguments,
+    accuracy: NutationAccuracy,
+) -> (f64, f64) {
+    let (dp, de) = match accuracy {
+        NutationAccuracy::Full => return nut06a_with_args(fa),
+        NutationAccuracy::Truncated => nut00a::nut00a_truncated(fa, 77, 0),
+        NutationAccuracy::Low => nut00a::nut00a_truncated(fa, 150, 20),
+    };
+
+    /* Apply P03 adjustments (Wallace & Capitaine, 2006, Eqs.5). */
+    let fj2 = -2.7774e-6 * fa.t;
+    (dp + dp * (0.4697e-6 + fj2), de + de * fj2)
+}
+
 /// Form rotation matrix given the Fukushima-Williams angles. (`eraFw2m`)
 ///
 /// Given:
@@ -563,10 +702,10 @@ pub fn nut06a(date1: f64, date2: f64) -> (f64, f64) {
 /// * Capitaine, N. & Wallace, P.T., 2006, Astron.Astrophys. 450, 855
 /// * Hilton, J. et al., 2006, Celest.Mech.Dyn.Astron. 94, 351
 ///
-pub fn fw_to_matrix(gamb: f64, phib: f64, psi: f64, eps: f64) -> [[f64; 3]; 3] {
+pub fn fw_to_matrix<R: Real>(gamb: R, phib: R, psi: R, eps: R) -> [[R; 3]; 3] {
     use crate::vectors_and_matrices::{init_matrix, rotate_x, rotate_z};
 
-    let mut r = [[0.0; 3]; 3];
+    let mut r = [[R::from_f64(0.0); 3]; 3];
 
     /* Construct the matrix. */
     init_matrix(&mut r);
@@ -617,12 +756,56 @@ pub fn fw_to_matrix(gamb: f64, phib: f64, psi: f64, eps: f64) -> [[f64; 3]; 3] {
 ///
 /// * Capitaine, N. & Wallace, P.T., 2006, Astron.Astrophys. 450, 855.
 ///
-pub fn pn_matrix_06a(date1: f64, date2: f64) -> [[f64; 3]; 3] {
+pub fn pn_matrix_06a<R: Real>(date1: R, date2: R) -> [[R; 3]; 3] {
+    pn_matrix_06a_with_args(&FundamentalArguments::new(date1, date2))
+}
+
+/// Form the matrix of precession-nutation (including frame bias), IAU 2006
+/// precession and IAU 2000A nutation models, given precomputed fundamental
+/// arguments. See [`pn_matrix_06a`]; the results are identical.
+pub fn pn_matrix_06a_with_args<R: Real>(fa: &FundamentalArguments<R>) -> [[R; 3]; 3] {
+    /* Fukushima-Williams angles for frame bias and precession. */
+    let PrecessionAnglesFw06 {
+        gamb,
+        phib,
+        psib,
+        epsa,
+    } = precession_angles_fw06_t(fa.t);
+
+    /* Nutation components. */
+    let (dp, de) = nut06a_with_args(fa);
+
+    /* Equinox based nutation x precession x bias matrix. */
+    fw_to_matrix(gamb, phib, psib + dp, epsa + de)
+}
+
+/// Form the matrix of precession-nutation (including frame bias), IAU 2006
+/// precession and IAU 2000A nutation evaluated to a chosen
+/// [`NutationAccuracy`]. With [`NutationAccuracy::Full`], this is
+/// [`pn_matrix_06a`].
+pub fn pn_matrix_06a_with_accuracy(
+    date1: f64,
+    date2: f64,
+    accuracy: NutationAccuracy,
+) -> [[f64; 3]; 3] {
+    pn_matrix_06a_with_args_accuracy(&FundamentalArguments::new(date1, date2), accuracy)
+}
+
+/// [`pn_matrix_06a_with_accuracy`] given precomputed fundamental arguments.
+pub(crate) fn pn_matrix_06a_with_args_accuracy(
+    fa: &FundamentalArguments,
+    accuracy: NutationAccuracy,
+) -> [[f64; 3]; 3] {
     /* Fukushima-Williams angles for frame bias and precession. */
-    let (gamb, phib, psib, epsa) = precession_angles_fw06(date1, date2);
+    let PrecessionAnglesFw06 {
+        gamb,
+        phib,
+        psib,
+        epsa,
+    } = precession_angles_fw06_t(fa.t);
 
     /* Nutation components. */
-    let (dp, de) = nut06a(date1, date2);
+    let (dp, de) = nut06a_with_args_accuracy(fa, accuracy);
 
     /* Equinox based nutation x precession x bias matrix. */
     fw_to_matrix(gamb, phib, psib + dp, epsa + de)
@@ -655,7 +838,7 @@ pub fn pn_matrix_06a(date1: f64, date2: f64) -> [[f64; 3]; 3] {
 /// n.b. The celestial ephemeris origin (CEO) was renamed "celestial
 ///      intermediate origin" (CIO) by IAU 2006 Resolution 2.
 ///
-pub fn bpn_to_xy(rbpn: [[f64; 3]; 3]) -> (f64, f64) {
+pub fn bpn_to_xy<R: Real>(rbpn: [[R; 3]; 3]) -> (R, R) {
     (rbpn[2][0], rbpn[2][1])
 }
 
@@ -685,16 +868,17 @@ pub fn bpn_to_xy(rbpn: [[f64; 3]; 3]) -> (f64, f64) {
 /// * Capitaine, N. & Wallace, P.T., 2006, Astron.Astrophys. 450, 855
 /// * Wallace, P. & Capitaine, N., 2006, Astron.Astrophys. 459, 981
 ///
-pub fn eors(rnpb: [[f64; 3]; 3], s: f64) -> f64 {
+pub fn eors<R: Real>(rnpb: [[R; 3]; 3], s: R) -> R {
     /* Evaluate Wallace & Capitaine (2006) expression (16). */
     let x = rnpb[2][0];
-    let ax = x / (1.0 + rnpb[2][2]);
-    let xs = 1.0 - ax * x;
+    let ax = x / (rnpb[2][2] + 1.0);
+    let xs = R::from_f64(1.0) - ax * x;
     let ys = -ax * rnpb[2][1];
     let zs = -x;
     let p = rnpb[0][0] * xs + rnpb[0][1] * ys + rnpb[0][2] * zs;
     let q = rnpb[1][0] * xs + rnpb[1][1] * ys + rnpb[1][2] * zs;
-    if (p != 0.0) || (q != 0.0) {
+    let zero = R::from_f64(0.0);
+    if (p != zero) || (q != zero) {
         s - q.atan2(p)
     } else {
         s
@@ -744,9 +928,14 @@ pub fn eors(rnpb: [[f64; 3]; 3], s: f64) -> f64 {
 ///
 /// * Wallace, P.T. & Capitaine, N., 2006, Astron.Astrophys. 459, 981
 ///
-pub fn precession_matrix_06(date1: f64, date2: f64) -> [[f64; 3]; 3] {
+pub fn precession_matrix_06<R: Real>(date1: R, date2: R) -> [[R; 3]; 3] {
     /* Bias-precession Fukushima-Williams angles. */
-    let (gamb, phib, psib, epsa) = precession_angles_fw06(date1, date2);
+    let PrecessionAnglesFw06 {
+        gamb,
+        phib,
+        psib,
+        epsa,
+    } = precession_angles_fw06(date1, date2);
 
     /* Form the matrix. */
     fw_to_matrix(gamb, phib, psib, epsa)
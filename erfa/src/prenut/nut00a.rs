@@ -4,7 +4,10 @@
 
 use crate::{
     constants::*,
-    fundamental_argument::{e03, f03, ju03, l03, ma03, me03, om03, pa03, sa03, ur03, ve03},
+    fundamental_argument::FundamentalArguments,
+    math,
+    real::{polynomial, Real},
+    series::{accumulate, Accumulator, PoissonTerm, Sequential},
 };
 
 /// Nutation, IAU 2000A model (MHB2000 luni-solar and planetary nutation with
@@ -128,7 +131,13 @@ use crate::{
 /// * Wallace, P.T., "Software for Implementing the IAU 2000 Resolutions", in
 ///   IERS Workshop 5.1 (2002)
 ///
-pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
+pub fn nut00a<R: Real>(date1: R, date2: R) -> (R, R) {
+    nut00a_with_args(&FundamentalArguments::new(date1, date2))
+}
+
+/// Nutation, IAU 2000A model, given precomputed fundamental arguments. See
+/// [`nut00a`]; the results are identical.
+pub fn nut00a_with_args<R: Real>(fa: &FundamentalArguments<R>) -> (R, R) {
     /* Units of 0.1 microarcsecond to radians */
     const U2R: f64 = ERFA_DAS2R / 1e7;
 
@@ -136,40 +145,164 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
     /* Luni-Solar nutation model */
     /* ------------------------- */
 
-    /* Interval between fundamental date J2000.0 and given date (JC). */
-    let t = ((date1 - ERFA_DJ00) + date2) / ERFA_DJC;
+    /* Interval between fundamental date J2000.0 and given date (JC), and the */
+    /* fundamental arguments. */
+    let args = NutationArguments::from_fundamental(fa);
 
     /* ------------------- */
     /* LUNI-SOLAR NUTATION */
     /* ------------------- */
 
-    /* Fundamental (Delaunay) arguments */
+    let NutationArguments {
+        t,
+        el,
+        elp,
+        f,
+        d,
+        om,
+        ..
+    } = args;
+
+    /* Summation of luni-solar nutation series (in reverse order). */
+    let mut sums = [Sequential::new(R::from_f64(0.0)); 2];
+    accumulate(XLS.iter().rev(), t, &[el, elp, f, d, om], &mut sums);
+
+    /* Convert from 0.1 microarcsec units to radians. */
+    let dpsils = sums[0].sum() * U2R;
+    let depsls = sums[1].sum() * U2R;
+
+    /* ------------------ */
+    /* PLANETARY NUTATION */
+    /* ------------------ */
+
+    /* n.b.  The MHB2000 code computes the luni-solar and planetary nutation */
+    /* in different functions, using slightly different Delaunay */
+    /* arguments in the two cases.  This behaviour is faithfully */
+    /* reproduced here.  Use of the IERS 2003 expressions for both */
+    /* cases leads to negligible changes, well below */
+    /* 0.1 microarcsecond. */
+
+    let NutationArguments {
+        al,
+        af,
+        ad,
+        aom,
+        apa,
+        alme,
+        alve,
+        alea,
+        alma,
+        alju,
+        alsa,
+        alur,
+        alne,
+        ..
+    } = args;
+
+    /* Summation of planetary nutation series (in reverse order). */
+    let mut sums = [Sequential::new(R::from_f64(0.0)); 2];
+    let args = [
+        al, af, ad, aom, alme, alve, alea, alma, alju, alsa, alur, alne, apa,
+    ];
+    accumulate(XPL.iter().rev(), t, &args, &mut sums);
+
+    /* Convert from 0.1 microarcsec units to radians. */
+    let dpsipl = sums[0].sum() * U2R;
+    let depspl = sums[1].sum() * U2R;
+
+    /* ------- */
+    /* RESULTS */
+    /* ------- */
+
+    /* Add luni-solar and planetary components. */
+    let dpsi = dpsils + dpsipl;
+    let deps = depsls + depspl;
+
+    (dpsi, deps)
+}
+
+/// Nutation from the `n_ls` largest terms of the IAU 2000A luni-solar series
+/// and the `n_pl` largest terms of the planetary series. With no planetary
+/// terms, the planetary series is replaced by the fixed offsets of the IAU
+/// 2000B model. Used for the reduced
+/// [`NutationAccuracy`](super::NutationAccuracy) levels.
+///
+/// The IERS 2003 fundamental arguments are used throughout, rather than the
+/// MHB2000 expressions of [`nut00a`] or the linear ones of IAU 2000B; the
+/// difference is far below the truncation error.
+pub(super) fn nut00a_truncated(fa: &FundamentalArguments, n_ls: usize, n_pl: usize) -> (f64, f64) {
+    /* Units of 0.1 microarcsecond to radians */
+    const U2R: f64 = ERFA_DAS2R / 1e7;
 
-    /* Mean anomaly of the Moon (IERS 2003). */
-    let el = l03(t);
+    /* Correction for the planetary nutation (IAU 2000B, milliarcsec). */
+    const DPPLAN: f64 = -0.135 * ERFA_DMAS2R;
+    const DEPLAN: f64 = 0.388 * ERFA_DMAS2R;
 
-    /* Mean anomaly of the Sun (MHB2000). */
-    #[rustfmt::skip]
-  let elp = (1287104.79305  +
-           t * (129596581.0481  +
-           t * (-0.5532  +
-           t * (0.000136  +
-           t * (-0.00001149)))) % ERFA_TURNAS) * ERFA_DAS2R;
+    /* Summation of luni-solar nutation series (in reverse order). */
+    let mut sums = [Sequential::new(0.0), Sequential::new(0.0)];
+    let args = [fa.l, fa.lp, fa.f, fa.d, fa.om];
+    accumulate(XLS[..n_ls].iter().rev(), fa.t, &args, &mut sums);
+    let dpsils = sums[0].sum() * U2R;
+    let depsls = sums[1].sum() * U2R;
+
+    if n_pl == 0 {
+        /* Add the fixed offsets to account for the planetary terms. */
+        return (dpsils + DPPLAN, depsls + DEPLAN);
+    }
+
+    /* Summation of the largest planetary terms (in reverse order). */
+    let mut sums = [Sequential::new(0.0), Sequential::new(0.0)];
+    let args = [
+        fa.l, fa.f, fa.d, fa.om, fa.me, fa.ve, fa.e, fa.ma, fa.ju, fa.sa, fa.ur, fa.ne, fa.pa,
+    ];
+    let terms = XPL_LARGEST[..n_pl].iter().rev().map(|&i| &XPL[i]);
+    accumulate(terms, fa.t, &args, &mut sums);
+
+    (dpsils + sums[0].sum() * U2R, depsls + sums[1].sum() * U2R)
+}
+
+/// Nutation, IAU 2000A model, evaluated with angle-addition formulas rather
+/// than a `sin_cos` per term.
+///
+/// Given:
+///  * `date1`,`date2`: TT as a 2-part Julian Date
+///
+/// Returned:
+///  * `dpsi`,`deps`: nutation, luni-solar + planetary
+///
+/// # Notes:
+///
+/// 1) This is the same model as [`nut00a`], and it takes the same arguments;
+///    see that function for details.
+///
+/// 2) The sines and cosines of the integer multiples of each fundamental
+///    argument that appear in the series are computed once per call; the
+///    sine and cosine of each term's argument are then formed from them with
+///    the angle-addition formulas. This replaces about 1365 `sin_cos`
+///    evaluations with about 120, and is 2-3 times faster.
+///
+/// 3) The results differ from [`nut00a`] only by rounding; over the years
+///    0-4000 the difference is less than 1e-19 radians (2e-8
+///    microarcseconds) in both `dpsi` and `deps`, far below the accuracy of
+///    the model.
+///
+pub fn nut00a_fast(date1: f64, date2: f64) -> (f64, f64) {
+    /* Units of 0.1 microarcsecond to radians */
+    const U2R: f64 = ERFA_DAS2R / 1e7;
 
-    /* Mean longitude of the Moon minus that of the ascending node */
-    /* (IERS 2003. */
-    let f = f03(t);
+    let args = NutationArguments::new(date1, date2);
+    let t = args.t;
 
-    /* Mean elongation of the Moon from the Sun (MHB2000). */
-    #[rustfmt::skip]
-  let d = (1072260.70369  +
-         t * (1602961601.2090  +
-         t * (-6.3706  +
-         t * (0.006593  +
-         t * (-0.00003169)))) % ERFA_TURNAS) * ERFA_DAS2R;
+    /* ------------------- */
+    /* LUNI-SOLAR NUTATION */
+    /* ------------------- */
 
-    /* Mean longitude of the ascending node of the Moon (IERS 2003). */
-    let om = om03(t);
+    /* Sines and cosines of multiples of the arguments. */
+    let el = Multiples::new(args.el, 5);
+    let elp = Multiples::new(args.elp, 3);
+    let f = Multiples::new(args.f, 4);
+    let d = Multiples::new(args.d, 6);
+    let om = Multiples::new(args.om, 4);
 
     /* Initialize the nutation values. */
     let mut dp = 0.0;
@@ -177,14 +310,16 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
 
     /* Summation of luni-solar nutation series (in reverse order). */
     for xls in XLS.iter().rev() {
-        /* Argument and functions. */
-        let arg = (xls.nl as f64 * el
-            + xls.nlp as f64 * elp
-            + xls.nf as f64 * f
-            + xls.nd as f64 * d
-            + xls.nom as f64 * om)
-            % ERFA_D2PI;
-        let (sarg, carg) = arg.sin_cos();
+        /* Argument functions, by angle addition. */
+        let (sarg, carg) = [
+            (&el, xls.nl),
+            (&elp, xls.nlp),
+            (&f, xls.nf),
+            (&d, xls.nd),
+            (&om, xls.nom),
+        ]
+        .into_iter()
+        .fold((0.0, 1.0), |sc, (m, n)| add_angles(sc, m, n));
 
         /* Term. */
         dp += (xls.sp + xls.spt * t) * sarg + xls.cp * carg;
@@ -199,40 +334,20 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
     /* PLANETARY NUTATION */
     /* ------------------ */
 
-    /* n.b.  The MHB2000 code computes the luni-solar and planetary nutation */
-    /* in different functions, using slightly different Delaunay */
-    /* arguments in the two cases.  This behaviour is faithfully */
-    /* reproduced here.  Use of the IERS 2003 expressions for both */
-    /* cases leads to negligible changes, well below */
-    /* 0.1 microarcsecond. */
-
-    /* Mean anomaly of the Moon (MHB2000). */
-    let al = (2.35555598 + 8328.6914269554 * t) % ERFA_D2PI;
-
-    /* Mean longitude of the Moon minus that of the ascending node */
-    /*(MHB2000). */
-    let af = (1.627905234 + 8433.466158131 * t) % ERFA_D2PI;
-
-    /* Mean elongation of the Moon from the Sun (MHB2000). */
-    let ad = (5.198466741 + 7771.3771468121 * t) % ERFA_D2PI;
-
-    /* Mean longitude of the ascending node of the Moon (MHB2000). */
-    let aom = (2.18243920 - 33.757045 * t) % ERFA_D2PI;
-
-    /* General accumulated precession in longitude (IERS 2003). */
-    let apa = pa03(t);
-
-    /* Planetary longitudes, Mercury through Uranus (IERS 2003). */
-    let alme = me03(t);
-    let alve = ve03(t);
-    let alea = e03(t);
-    let alma = ma03(t);
-    let alju = ju03(t);
-    let alsa = sa03(t);
-    let alur = ur03(t);
-
-    /* Neptune longitude (MHB2000). */
-    let alne = (5.321159000 + 3.8127774000 * t) % ERFA_D2PI;
+    /* Sines and cosines of multiples of the arguments. */
+    let al = Multiples::new(args.al, 5);
+    let af = Multiples::new(args.af, 4);
+    let ad = Multiples::new(args.ad, 6);
+    let aom = Multiples::new(args.aom, 4);
+    let alme = Multiples::new(args.alme, 1);
+    let alve = Multiples::new(args.alve, 20);
+    let alea = Multiples::new(args.alea, 21);
+    let alma = Multiples::new(args.alma, 17);
+    let alju = Multiples::new(args.alju, 5);
+    let alsa = Multiples::new(args.alsa, 10);
+    let alur = Multiples::new(args.alur, 3);
+    let alne = Multiples::new(args.alne, 2);
+    let apa = Multiples::new(args.apa, 2);
 
     /* Initialize the nutation values. */
     let mut dp = 0.0;
@@ -240,22 +355,24 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
 
     /* Summation of planetary nutation series (in reverse order). */
     for xpl in XPL.iter().rev() {
-        /* Argument and functions. */
-        let arg = (xpl.nl as f64 * al
-            + xpl.nf as f64 * af
-            + xpl.nd as f64 * ad
-            + xpl.nom as f64 * aom
-            + xpl.nme as f64 * alme
-            + xpl.nve as f64 * alve
-            + xpl.nea as f64 * alea
-            + xpl.nma as f64 * alma
-            + xpl.nju as f64 * alju
-            + xpl.nsa as f64 * alsa
-            + xpl.nur as f64 * alur
-            + xpl.nne as f64 * alne
-            + xpl.npa as f64 * apa)
-            % ERFA_D2PI;
-        let (sarg, carg) = arg.sin_cos();
+        /* Argument functions, by angle addition. */
+        let (sarg, carg) = [
+            (&al, xpl.nl),
+            (&af, xpl.nf),
+            (&ad, xpl.nd),
+            (&aom, xpl.nom),
+            (&alme, xpl.nme),
+            (&alve, xpl.nve),
+            (&alea, xpl.nea),
+            (&alma, xpl.nma),
+            (&alju, xpl.nju),
+            (&alsa, xpl.nsa),
+            (&alur, xpl.nur),
+            (&alne, xpl.nne),
+            (&apa, xpl.npa),
+        ]
+        .into_iter()
+        .fold((0.0, 1.0), |sc, (m, n)| add_angles(sc, m, n));
 
         /* Term. */
         dp += xpl.sp as f64 * sarg + xpl.cp as f64 * carg;
@@ -266,31 +383,180 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
     let dpsipl = dp * U2R;
     let depspl = de * U2R;
 
-    /* ------- */
-    /* RESULTS */
-    /* ------- */
-
     /* Add luni-solar and planetary components. */
-    let dpsi = dpsils + dpsipl;
-    let deps = depsls + depspl;
+    (dpsils + dpsipl, depsls + depspl)
+}
 
-    (dpsi, deps)
+/// Sines and cosines of the multiples 1..=n of an angle, for [`nut00a_fast`].
+struct Multiples {
+    sc: [(f64, f64); 21],
+}
+
+impl Multiples {
+    fn new(x: f64, n: usize) -> Self {
+        let mut sc = [(0.0, 1.0); 21];
+        for (k, sc) in sc.iter_mut().enumerate().take(n) {
+            *sc = math::sin_cos(((k + 1) as f64 * x) % ERFA_D2PI);
+        }
+        Self { sc }
+    }
+}
+
+/// Add `n` times the angle in `m` to the angle with sine and cosine `sc`.
+#[inline]
+fn add_angles((s, c): (f64, f64), m: &Multiples, n: i32) -> (f64, f64) {
+    if n == 0 {
+        return (s, c);
+    }
+    let (sn, cn) = m.sc[n.unsigned_abs() as usize - 1];
+    let sn = if n < 0 { -sn } else { sn };
+    (s * cn + c * sn, c * cn - s * sn)
+}
+
+/// The fundamental arguments used by [`nut00a`], for one date.
+#[derive(Clone, Copy)]
+pub(super) struct NutationArguments<R = f64> {
+    pub(super) t: R,
+    pub(super) el: R,
+    pub(super) elp: R,
+    pub(super) f: R,
+    pub(super) d: R,
+    pub(super) om: R,
+    pub(super) al: R,
+    pub(super) af: R,
+    pub(super) ad: R,
+    pub(super) aom: R,
+    pub(super) apa: R,
+    pub(super) alme: R,
+    pub(super) alve: R,
+    pub(super) alea: R,
+    pub(super) alma: R,
+    pub(super) alju: R,
+    pub(super) alsa: R,
+    pub(super) alur: R,
+    pub(super) alne: R,
+}
+
+impl<R: Real> NutationArguments<R> {
+    pub(super) fn new(date1: R, date2: R) -> Self {
+        Self::from_fundamental(&FundamentalArguments::new(date1, date2))
+    }
+
+    pub(super) fn from_fundamental(fa: &FundamentalArguments<R>) -> Self {
+        let t = fa.t;
+
+        /* Fundamental (Delaunay) arguments for the luni-solar series */
+
+        /* Mean anomaly of the Moon (IERS 2003). */
+        let el = fa.l;
+
+        /* Mean anomaly of the Sun (MHB2000). */
+        let elp = (t * polynomial(t, &[129596581.0481, -0.5532, 0.000136, -0.00001149])
+            % ERFA_TURNAS
+            + 1287104.79305)
+            * ERFA_DAS2R;
+
+        /* Mean longitude of the Moon minus that of the ascending node */
+        /* (IERS 2003. */
+        let f = fa.f;
+
+        /* Mean elongation of the Moon from the Sun (MHB2000). */
+        let d = (t * polynomial(t, &[1602961601.2090, -6.3706, 0.006593, -0.00003169])
+            % ERFA_TURNAS
+            + 1072260.70369)
+            * ERFA_DAS2R;
+
+        /* Mean longitude of the ascending node of the Moon (IERS 2003). */
+        let om = fa.om;
+
+        /* Arguments for the planetary series (see the note in nut00a). */
+
+        /* Mean anomaly of the Moon (MHB2000). */
+        let al = polynomial(t, &[2.35555598, 8328.6914269554]) % ERFA_D2PI;
+
+        /* Mean longitude of the Moon minus that of the ascending node */
+        /*(MHB2000). */
+        let af = polynomial(t, &[1.627905234, 8433.466158131]) % ERFA_D2PI;
+
+        /* Mean elongation of the Moon from the Sun (MHB2000). */
+        let ad = polynomial(t, &[5.198466741, 7771.3771468121]) % ERFA_D2PI;
+
+        /* Mean longitude of the ascending node of the Moon (MHB2000). */
+        let aom = polynomial(t, &[2.18243920, -33.757045]) % ERFA_D2PI;
+
+        /* General accumulated precession in longitude (IERS 2003). */
+        let apa = fa.pa;
+
+        /* Planetary longitudes, Mercury through Uranus (IERS 2003). */
+        let alme = fa.me;
+        let alve = fa.ve;
+        let alea = fa.e;
+        let alma = fa.ma;
+        let alju = fa.ju;
+        let alsa = fa.sa;
+        let alur = fa.ur;
+
+        /* Neptune longitude (MHB2000). */
+        let alne = polynomial(t, &[5.321159000, 3.8127774000]) % ERFA_D2PI;
+
+        Self {
+            t,
+            el,
+            elp,
+            f,
+            d,
+            om,
+            al,
+            af,
+            ad,
+            aom,
+            apa,
+            alme,
+            alve,
+            alea,
+            alma,
+            alju,
+            alsa,
+            alur,
+            alne,
+        }
+    }
+}
+
+pub(super) struct Xls {
+    pub(super) nl: i32,
+    pub(super) nlp: i32,
+    pub(super) nf: i32,
+    pub(super) nd: i32,
+    pub(super) nom: i32,
+    pub(super) sp: f64,
+    pub(super) spt: f64,
+    pub(super) cp: f64,
+    pub(super) ce: f64,
+    pub(super) cet: f64,
+    pub(super) se: f64,
 }
+impl<R: Real> PoissonTerm<2, R> for Xls {
+    /// The argument, given l, l', F, D and Om.
+    fn argument(&self, _t: R, args: &[R]) -> R {
+        (args[0] * self.nl as f64
+            + args[1] * self.nlp as f64
+            + args[2] * self.nf as f64
+            + args[3] * self.nd as f64
+            + args[4] * self.nom as f64)
+            % ERFA_D2PI
+    }
 
-struct Xls {
-    nl: i32,
-    nlp: i32,
-    nf: i32,
-    nd: i32,
-    nom: i32,
-    sp: f64,
-    spt: f64,
-    cp: f64,
-    ce: f64,
-    cet: f64,
-    se: f64,
+    /// The contributions to dpsi and deps.
+    fn contributions(&self, t: R, sarg: R, carg: R) -> [R; 2] {
+        [
+            (t * self.spt + self.sp) * sarg + carg * self.cp,
+            (t * self.cet + self.ce) * carg + sarg * self.se,
+        ]
+    }
 }
-const XLS: [Xls; 678] = [
+
+pub(super) const XLS: [Xls; 678] = [
     Xls {
         nl: 0,
         nlp: 0,
@@ -9107,27 +9373,62 @@ const XLS: [Xls; 678] = [
     },
 ];
 
-struct Xpl {
-    nl: i32,
-    nf: i32,
-    nd: i32,
-    nom: i32,
-    nme: i32,
-    nve: i32,
-    nea: i32,
-    nma: i32,
-    nju: i32,
-    nsa: i32,
-    nur: i32,
-    nne: i32,
-    npa: i32,
-    sp: i32,
-    cp: i32,
-    se: i32,
-    ce: i32,
+pub(super) struct Xpl {
+    pub(super) nl: i32,
+    pub(super) nf: i32,
+    pub(super) nd: i32,
+    pub(super) nom: i32,
+    pub(super) nme: i32,
+    pub(super) nve: i32,
+    pub(super) nea: i32,
+    pub(super) nma: i32,
+    pub(super) nju: i32,
+    pub(super) nsa: i32,
+    pub(super) nur: i32,
+    pub(super) nne: i32,
+    pub(super) npa: i32,
+    pub(super) sp: i32,
+    pub(super) cp: i32,
+    pub(super) se: i32,
+    pub(super) ce: i32,
 }
 
-const XPL: [Xpl; 687] = [
+impl<R: Real> PoissonTerm<2, R> for Xpl {
+    /// The argument, given the 13 arguments of the planetary series in the
+    /// order of the multipliers.
+    fn argument(&self, _t: R, args: &[R]) -> R {
+        (args[0] * self.nl as f64
+            + args[1] * self.nf as f64
+            + args[2] * self.nd as f64
+            + args[3] * self.nom as f64
+            + args[4] * self.nme as f64
+            + args[5] * self.nve as f64
+            + args[6] * self.nea as f64
+            + args[7] * self.nma as f64
+            + args[8] * self.nju as f64
+            + args[9] * self.nsa as f64
+            + args[10] * self.nur as f64
+            + args[11] * self.nne as f64
+            + args[12] * self.npa as f64)
+            % ERFA_D2PI
+    }
+
+    /// The contributions to dpsi and deps.
+    fn contributions(&self, _t: R, sarg: R, carg: R) -> [R; 2] {
+        [
+            sarg * self.sp as f64 + carg * self.cp as f64,
+            sarg * self.se as f64 + carg * self.ce as f64,
+        ]
+    }
+}
+
+/// Indices in [`XPL`] of its 20 largest terms (by amplitude in dpsi or deps),
+/// largest first.
+pub(super) const XPL_LARGEST: [usize; 20] = [
+    18, 19, 209, 9, 321, 0, 37, 364, 242, 47, 152, 270, 411, 541, 17, 305, 16, 36, 341, 145,
+];
+
+pub(super) const XPL: [Xpl; 687] = [
     Xpl {
         nl: 0,
         nf: 0,
@@ -0,0 +1,208 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Nutation for several dates at once, evaluating the series with portable
+//! SIMD (the `wide` crate). Requires the `wide` feature.
+//!
+//! The fundamental arguments are computed with the same (scalar) code as
+//! [`nut00a`](super::nut00a), but the series use a vectorised `sin_cos`, which
+//! does its own range reduction and is accurate to a couple of ulp rather than
+//! correctly rounded. The results therefore differ from
+//! [`nut00a`](super::nut00a) in the last few bits; the difference is below
+//! 1e-18 radians (about 2e-7 microarcseconds), far below the accuracy of the
+//! model.
+//!
+//! The vectorised `sin_cos` uses fused multiply-adds when the target supports
+//! them, so with the `reproducible` feature each lane's `sin_cos` is instead
+//! computed with the same software implementation as the scalar functions.
+
+use wide::f64x4;
+
+use super::nut00a::{NutationArguments, XLS, XPL};
+#[cfg(feature = "reproducible")]
+use crate::math;
+use crate::{constants::ERFA_DAS2R, transform::batch::check_lengths};
+
+/// The sine and cosine of each lane of `x`.
+#[inline]
+fn sin_cos(x: f64x4) -> (f64x4, f64x4) {
+    #[cfg(feature = "reproducible")]
+    {
+        let sc = x.to_array().map(math::sin_cos);
+        (sc.map(|(s, _)| s).into(), sc.map(|(_, c)| c).into())
+    }
+    #[cfg(not(feature = "reproducible"))]
+    x.sin_cos()
+}
+
+/// Nutation, IAU 2000A model, for 4 TT dates at once. See
+/// [`nut00a`](super::nut00a).
+///
+/// Given:
+///  * `date1`,`date2`: TT as 2-part Julian Dates
+///
+/// Returned:
+///  * `dpsi`,`deps`: nutation, luni-solar + planetary, for each date
+///
+pub fn nut00a_x4(date1: [f64; 4], date2: [f64; 4]) -> ([f64; 4], [f64; 4]) {
+    /* Units of 0.1 microarcsecond to radians */
+    const U2R: f64 = ERFA_DAS2R / 1e7;
+
+    let args: [NutationArguments; 4] =
+        core::array::from_fn(|i| NutationArguments::new(date1[i], date2[i]));
+    let lanes = |arg: fn(&NutationArguments) -> f64| f64x4::from(args.map(|a| arg(&a)));
+
+    /* ------------------- */
+    /* LUNI-SOLAR NUTATION */
+    /* ------------------- */
+
+    let t = lanes(|a| a.t);
+    let el = lanes(|a| a.el);
+    let elp = lanes(|a| a.elp);
+    let f = lanes(|a| a.f);
+    let d = lanes(|a| a.d);
+    let om = lanes(|a| a.om);
+
+    /* Initialize the nutation values. */
+    let mut dp = f64x4::ZERO;
+    let mut de = f64x4::ZERO;
+
+    /* Summation of luni-solar nutation series (in reverse order). */
+    for xls in XLS.iter().rev() {
+        /* Argument and functions. */
+        let arg = el * xls.nl as f64
+            + elp * xls.nlp as f64
+            + f * xls.nf as f64
+            + d * xls.nd as f64
+            + om * xls.nom as f64;
+        let (sarg, carg) = sin_cos(arg);
+
+        /* Term. */
+        dp += (t * xls.spt + xls.sp) * sarg + carg * xls.cp;
+        de += (t * xls.cet + xls.ce) * carg + sarg * xls.se;
+    }
+
+    /* Convert from 0.1 microarcsec units to radians. */
+    let dpsils = dp * U2R;
+    let depsls = de * U2R;
+
+    /* ------------------ */
+    /* PLANETARY NUTATION */
+    /* ------------------ */
+
+    let al = lanes(|a| a.al);
+    let af = lanes(|a| a.af);
+    let ad = lanes(|a| a.ad);
+    let aom = lanes(|a| a.aom);
+    let apa = lanes(|a| a.apa);
+    let alme = lanes(|a| a.alme);
+    let alve = lanes(|a| a.alve);
+    let alea = lanes(|a| a.alea);
+    let alma = lanes(|a| a.alma);
+    let alju = lanes(|a| a.alju);
+    let alsa = lanes(|a| a.alsa);
+    let alur = lanes(|a| a.alur);
+    let alne = lanes(|a| a.alne);
+
+    /* Initialize the nutation values. */
+    let mut dp = f64x4::ZERO;
+    let mut de = f64x4::ZERO;
+
+    /* Summation of planetary nutation series (in reverse order). */
+    for xpl in XPL.iter().rev() {
+        /* Argument and functions. */
+        let arg = al * xpl.nl as f64
+            + af * xpl.nf as f64
+            + ad * xpl.nd as f64
+            + aom * xpl.nom as f64
+            + alme * xpl.nme as f64
+            + alve * xpl.nve as f64
+            + alea * xpl.nea as f64
+            + alma * xpl.nma as f64
+            + alju * xpl.nju as f64
+            + alsa * xpl.nsa as f64
+            + alur * xpl.nur as f64
+            + alne * xpl.nne as f64
+            + apa * xpl.npa as f64;
+        let (sarg, carg) = sin_cos(arg);
+
+        /* Term. */
+        dp += sarg * xpl.sp as f64 + carg * xpl.cp as f64;
+        de += sarg * xpl.se as f64 + carg * xpl.ce as f64;
+    }
+
+    /* Convert from 0.1 microarcsec units to radians. */
+    let dpsipl = dp * U2R;
+    let depspl = de * U2R;
+
+    /* ------- */
+    /* RESULTS */
+    /* ------- */
+
+    /* Add luni-solar and planetary components. */
+    let dpsi = dpsils + dpsipl;
+    let deps = depsls + depspl;
+
+    (dpsi.to_array(), deps.to_array())
+}
+
+/// Nutation, IAU 2000A model, for many TT dates, 4 at a time. See
+/// [`nut00a_x4`].
+///
+/// # Panics
+///
+/// This function panics if the slices don't have the same length.
+pub fn nut00a(date1: &[f64], date2: &[f64], dpsi: &mut [f64], deps: &mut [f64]) {
+    check_lengths(
+        "nut00a",
+        date1.len(),
+        &[date2.len(), dpsi.len(), deps.len()],
+    );
+    for (((date1, date2), dpsi), deps) in date1
+        .chunks(4)
+        .zip(date2.chunks(4))
+        .zip(dpsi.chunks_mut(4))
+        .zip(deps.chunks_mut(4))
+    {
+        /* Pad a short final chunk by repeating its last date. */
+        let n = date1.len();
+        let d1 = core::array::from_fn(|i| date1[i.min(n - 1)]);
+        let d2 = core::array::from_fn(|i| date2[i.min(n - 1)]);
+        let (p, e) = nut00a_x4(d1, d2);
+        dpsi.copy_from_slice(&p[..n]);
+        deps.copy_from_slice(&e[..n]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn test_nut00a_x4_matches_scalar() {
+        let date1 = [2400000.5, 2451545.0, 2400000.5, 2440000.5];
+        let date2 = [53736.0, -1421.3, 10000.25, 30000.75];
+        let (dpsi, deps) = nut00a_x4(date1, date2);
+        for i in 0..4 {
+            let (p, e) = super::super::nut00a(date1[i], date2[i]);
+            assert!((dpsi[i] - p).abs() < 1e-18, "{} {}", dpsi[i], p);
+            assert!((deps[i] - e).abs() < 1e-18, "{} {}", deps[i], e);
+        }
+    }
+
+    #[test]
+    fn test_nut00a_slices_match_x4() {
+        let date1 = vec![2400000.5; 7];
+        let date2: Vec<_> = (0..7).map(|i| 50000.0 + 123.4 * i as f64).collect();
+        let (mut dpsi, mut deps) = (vec![0.0; 7], vec![0.0; 7]);
+        nut00a(&date1, &date2, &mut dpsi, &mut deps);
+        for i in 0..7 {
+            let (p, e) = super::super::nut00a(date1[i], date2[i]);
+            assert!((dpsi[i] - p).abs() < 1e-18);
+            assert!((deps[i] - e).abs() < 1e-18);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Variants of the [`prenut`](super) functions that take [`Epoch`]s tagged
+//! with the time scale they require. See the untyped functions for full
+//! documentation; the results are identical.
+
+use crate::time::{Epoch, TT};
+
+/// Form the matrix of precession-nutation for a given date (including frame
+/// bias), equinox based, IAU 2006 precession and IAU 2000A nutation models.
+/// (`eraPnm06a`)
+///
+/// Given:
+/// * `tt`: TT epoch
+///
+/// Returned:
+/// * bias-precession-nutation matrix
+///
+/// See [`pn_matrix_06a`](super::pn_matrix_06a).
+///
+pub fn pn_matrix_06a(tt: Epoch<TT>) -> [[f64; 3]; 3] {
+    let (date1, date2) = tt.parts();
+    super::pn_matrix_06a(date1, date2)
+}
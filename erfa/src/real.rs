@@ -0,0 +1,375 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! The [`Real`] trait, for evaluating functions with numeric types other than
+//! `f64`.
+//!
+//! Several functions are generic over [`Real`], so that, for example, dual
+//! numbers can flow through them to give derivatives with respect to their
+//! inputs (automatic differentiation). These are:
+//!
+//! * the fundamental arguments ([`l03`](crate::fundamental_argument::l03) etc.
+//!   and [`FundamentalArguments`](crate::fundamental_argument::FundamentalArguments));
+//! * [`nut00a`](crate::prenut::nut00a), [`nut06a`](crate::prenut::nut06a),
+//!   [`obliquity_06`](crate::prenut::obliquity_06),
+//!   [`precession_angles_fw06`](crate::prenut::precession_angles_fw06),
+//!   [`fw_to_matrix`](crate::prenut::fw_to_matrix),
+//!   [`precession_matrix_06`](crate::prenut::precession_matrix_06),
+//!   [`pn_matrix_06a`](crate::prenut::pn_matrix_06a),
+//!   [`bpn_to_xy`](crate::prenut::bpn_to_xy) and
+//!   [`eors`](crate::prenut::eors);
+//! * [`S06`](crate::time::S06), [`gst06a`](crate::time::gst06a),
+//!   [`gst06`](crate::time::gst06), [`gmst06`](crate::time::gmst06) and
+//!   [`earth_rotation_angle_00`](crate::earth::earth_rotation_angle_00);
+//! * [`hadec_to_azel`](crate::transform::hadec_to_azel),
+//!   [`azel_to_hadec`](crate::transform::azel_to_hadec) and
+//!   [`geodetic_to_geocentric`](crate::transform::geodetic_to_geocentric);
+//! * [`norm_angle`](crate::misc::norm_angle) and the rotations in
+//!   [`vectors_and_matrices`](crate::vectors_and_matrices).
+//!
+//! `f64` implements [`Real`], and with `f64` these functions give exactly the
+//! same results as always. Model constants (e.g. the ellipsoid parameters and
+//! the series coefficients) remain `f64`.
+
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::math;
+
+/// A real number type: `f64`, or a type that carries extra information
+/// alongside one, such as a dual number for automatic differentiation.
+///
+/// Arithmetic is needed between values, and with `f64` constants on the
+/// right-hand side; `x % c` is the floating-point remainder (as for `f64`).
+/// Comparisons ([`PartialOrd`]) should compare the values that would have been
+/// computed with `f64`.
+pub trait Real:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Add<f64, Output = Self>
+    + Sub<f64, Output = Self>
+    + Mul<f64, Output = Self>
+    + Div<f64, Output = Self>
+    + Rem<f64, Output = Self>
+{
+    /// A constant.
+    fn from_f64(x: f64) -> Self;
+
+    /// Sine (radians).
+    fn sin(self) -> Self;
+
+    /// Cosine (radians).
+    fn cos(self) -> Self;
+
+    /// Sine and cosine (radians).
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    /// Arctangent (radians).
+    fn atan(self) -> Self;
+
+    /// Four-quadrant arctangent of `self / x` (radians).
+    fn atan2(self, x: Self) -> Self;
+
+    /// Square root.
+    fn sqrt(self) -> Self;
+}
+
+impl Real for f64 {
+    #[inline]
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        math::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        math::cos(self)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        math::sin_cos(self)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        math::atan(self)
+    }
+
+    #[inline]
+    fn atan2(self, x: Self) -> Self {
+        math::atan2(self, x)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        math::sqrt(self)
+    }
+}
+
+/// Evaluate the polynomial `c[0] + t * (c[1] + t * (c[2] + ...))` in that
+/// (Horner) order, as the ERFA C code writes them.
+#[inline]
+pub(crate) fn polynomial<R: Real>(t: R, c: &[f64]) -> R {
+    let (&last, rest) = c.split_last().expect("no coefficients");
+    rest.iter()
+        .rev()
+        .fold(R::from_f64(last), |acc, &c| t * acc + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+
+    use super::*;
+    use crate::{
+        constants::ERFA_D2PI,
+        ellipsoid::Ellipsoid,
+        prenut::precession_matrix_06,
+        time::gst06a,
+        transform::{geodetic_to_geocentric, hadec_to_azel},
+    };
+
+    /// A dual number `v + d ε` (with `ε² = 0`): `d` carries the derivative.
+    #[derive(Clone, Copy, Debug)]
+    struct Dual {
+        v: f64,
+        d: f64,
+    }
+
+    impl Dual {
+        fn var(v: f64) -> Self {
+            Self { v, d: 1.0 }
+        }
+    }
+
+    impl PartialEq for Dual {
+        fn eq(&self, other: &Self) -> bool {
+            self.v == other.v
+        }
+    }
+
+    impl PartialOrd for Dual {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            self.v.partial_cmp(&other.v)
+        }
+    }
+
+    impl Add for Dual {
+        type Output = Self;
+        fn add(self, o: Self) -> Self {
+            Self {
+                v: self.v + o.v,
+                d: self.d + o.d,
+            }
+        }
+    }
+
+    impl Sub for Dual {
+        type Output = Self;
+        fn sub(self, o: Self) -> Self {
+            Self {
+                v: self.v - o.v,
+                d: self.d - o.d,
+            }
+        }
+    }
+
+    impl Mul for Dual {
+        type Output = Self;
+        fn mul(self, o: Self) -> Self {
+            Self {
+                v: self.v * o.v,
+                d: self.d * o.v + self.v * o.d,
+            }
+        }
+    }
+
+    impl Div for Dual {
+        type Output = Self;
+        fn div(self, o: Self) -> Self {
+            Self {
+                v: self.v / o.v,
+                d: (self.d * o.v - self.v * o.d) / (o.v * o.v),
+            }
+        }
+    }
+
+    impl Neg for Dual {
+        type Output = Self;
+        fn neg(self) -> Self {
+            Self {
+                v: -self.v,
+                d: -self.d,
+            }
+        }
+    }
+
+    impl Add<f64> for Dual {
+        type Output = Self;
+        fn add(self, c: f64) -> Self {
+            self + Self::from_f64(c)
+        }
+    }
+
+    impl Sub<f64> for Dual {
+        type Output = Self;
+        fn sub(self, c: f64) -> Self {
+            self - Self::from_f64(c)
+        }
+    }
+
+    impl Mul<f64> for Dual {
+        type Output = Self;
+        fn mul(self, c: f64) -> Self {
+            self * Self::from_f64(c)
+        }
+    }
+
+    impl Div<f64> for Dual {
+        type Output = Self;
+        fn div(self, c: f64) -> Self {
+            self / Self::from_f64(c)
+        }
+    }
+
+    impl Rem<f64> for Dual {
+        type Output = Self;
+        fn rem(self, c: f64) -> Self {
+            Self {
+                v: self.v % c,
+                d: self.d,
+            }
+        }
+    }
+
+    impl Real for Dual {
+        fn from_f64(v: f64) -> Self {
+            Self { v, d: 0.0 }
+        }
+
+        fn sin(self) -> Self {
+            Self {
+                v: Real::sin(self.v),
+                d: self.d * Real::cos(self.v),
+            }
+        }
+
+        fn cos(self) -> Self {
+            Self {
+                v: Real::cos(self.v),
+                d: -self.d * Real::sin(self.v),
+            }
+        }
+
+        fn atan(self) -> Self {
+            Self {
+                v: Real::atan(self.v),
+                d: self.d / (1.0 + self.v * self.v),
+            }
+        }
+
+        fn atan2(self, x: Self) -> Self {
+            Self {
+                v: Real::atan2(self.v, x.v),
+                d: (x.v * self.d - self.v * x.d) / (x.v * x.v + self.v * self.v),
+            }
+        }
+
+        fn sqrt(self) -> Self {
+            let v = Real::sqrt(self.v);
+            Self {
+                v,
+                d: self.d / (2.0 * v),
+            }
+        }
+    }
+
+    /// The central-difference derivative of `f` at `x`.
+    fn numerical(f: impl Fn(f64) -> f64, x: f64, h: f64) -> f64 {
+        (f(x + h) - f(x - h)) / (2.0 * h)
+    }
+
+    #[test]
+    fn test_dual_hadec_to_azel() {
+        let (ha, dec, phi) = (0.7, -0.4, 0.6);
+        let (az, el) = hadec_to_azel(Dual::var(ha), Dual::from_f64(dec), Dual::from_f64(phi));
+
+        /* The values are those of f64; the derivatives are right. */
+        assert_eq!((az.v, el.v), hadec_to_azel(ha, dec, phi));
+        let daz = numerical(|ha| hadec_to_azel(ha, dec, phi).0, ha, 1e-6);
+        let del = numerical(|ha| hadec_to_azel(ha, dec, phi).1, ha, 1e-6);
+        assert_relative_eq!(az.d, daz, max_relative = 1e-8);
+        assert_relative_eq!(el.d, del, max_relative = 1e-8);
+    }
+
+    #[test]
+    fn test_dual_geodetic_to_geocentric() {
+        let (elong, phi, height) = (2.1, -0.45, 2500.0);
+        let xyz = geodetic_to_geocentric(
+            Ellipsoid::WGS84,
+            Dual::from_f64(elong),
+            Dual::from_f64(phi),
+            Dual::var(height),
+        )
+        .unwrap();
+
+        /* The derivative with respect to height is the unit normal. */
+        let normal = [phi.cos() * elong.cos(), phi.cos() * elong.sin(), phi.sin()];
+        for (p, n) in xyz.iter().zip(normal) {
+            assert_relative_eq!(p.d, n, max_relative = 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_dual_gst06a() {
+        let (uta, utb, tta, ttb) = (2400000.5, 53736.0, 2400000.5, 53736.0008);
+        let gst = gst06a(
+            Dual::from_f64(uta),
+            Dual::var(utb),
+            Dual::from_f64(tta),
+            Dual::from_f64(ttb),
+        );
+        assert_eq!(gst.v, gst06a(uta, utb, tta, ttb));
+
+        /* GST advances by the ratio of sidereal to solar time. */
+        assert_relative_eq!(gst.d, ERFA_D2PI * 1.0027378119113545, max_relative = 1e-15);
+
+        /* With respect to TT, only precession-nutation contributes. */
+        let gst = gst06a(
+            Dual::from_f64(uta),
+            Dual::from_f64(utb),
+            Dual::from_f64(tta),
+            Dual::var(ttb),
+        );
+        let expected = numerical(|ttb| gst06a(uta, utb, tta, ttb), ttb, 0.01);
+        assert_relative_eq!(gst.d, expected, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn test_dual_precession_matrix_06() {
+        let (date1, date2) = (2451545.0, 3000.5);
+        let r = precession_matrix_06(Dual::from_f64(date1), Dual::var(date2));
+        let r64 = precession_matrix_06(date1, date2);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(r[i][j].v, r64[i][j]);
+                let expected = numerical(|d| precession_matrix_06(date1, d)[i][j], date2, 10.0);
+                assert_abs_diff_eq!(r[i][j].d, expected, epsilon = 1e-14);
+            }
+        }
+    }
+}
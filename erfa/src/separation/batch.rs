@@ -0,0 +1,122 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Variants of the [`separation`](super) functions that compare one reference
+//! point (e.g. a phase centre) with many others, writing into a slice of
+//! outputs. Terms that depend only on the reference point are computed once
+//! per call. The results are identical to calling the scalar functions element
+//! by element.
+//!
+//! # Panics
+//!
+//! All of these functions panic if the input and output slices don't have the
+//! same length.
+
+use super::{position_angle_spherical_coords_sc, PapReference};
+use crate::{
+    math,
+    transform::{batch::check_lengths, spherical_to_cartesian},
+};
+
+/// Angular separations of many points from the point (`a_long`, `a_lat`). See
+/// [`sep_spherical_coords`](super::sep_spherical_coords).
+pub fn sep_spherical_coords(
+    a_long: f64,
+    a_lat: f64,
+    b_long: &[f64],
+    b_lat: &[f64],
+    sep: &mut [f64],
+) {
+    check_lengths(
+        "sep_spherical_coords",
+        b_long.len(),
+        &[b_lat.len(), sep.len()],
+    );
+    let ac = spherical_to_cartesian(a_long, a_lat);
+    for ((&b_long, &b_lat), sep) in b_long.iter().zip(b_lat).zip(sep) {
+        *sep = super::sep_vectors(ac, spherical_to_cartesian(b_long, b_lat));
+    }
+}
+
+/// Angular separations of many p-vectors from the p-vector `a`. See
+/// [`sep_vectors`](super::sep_vectors).
+pub fn sep_vectors(a: [f64; 3], b: &[[f64; 3]], sep: &mut [f64]) {
+    check_lengths("sep_vectors", b.len(), &[sep.len()]);
+    for (&b, sep) in b.iter().zip(sep) {
+        *sep = super::sep_vectors(a, b);
+    }
+}
+
+/// Position angles of many p-vectors with respect to the p-vector `a`. See
+/// [`position_angle_vectors`](super::position_angle_vectors).
+pub fn position_angle_vectors(a: [f64; 3], b: &[[f64; 3]], pa: &mut [f64]) {
+    check_lengths("position_angle_vectors", b.len(), &[pa.len()]);
+    let a = PapReference::new(a);
+    for (&b, pa) in b.iter().zip(pa) {
+        *pa = a.position_angle(b);
+    }
+}
+
+/// Position angles of many points with respect to the point (`al`, `ap`). See
+/// [`position_angle_spherical_coords`](super::position_angle_spherical_coords).
+pub fn position_angle_spherical_coords(al: f64, ap: f64, bl: &[f64], bp: &[f64], pa: &mut [f64]) {
+    check_lengths(
+        "position_angle_spherical_coords",
+        bl.len(),
+        &[bp.len(), pa.len()],
+    );
+    let (sap, cap) = math::sin_cos(ap);
+    for ((&bl, &bp), pa) in bl.iter().zip(bp).zip(pa) {
+        *pa = position_angle_spherical_coords_sc(al, sap, cap, bl, bp);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+    use crate::separation as scalar;
+
+    #[test]
+    fn test_separation_batches_match_scalar() {
+        let n = 101;
+        let bl: Vec<_> = (0..n).map(|i| -3.1 + 6.2 * i as f64 / n as f64).collect();
+        let bp: Vec<_> = (0..n)
+            .map(|i| -1.5 + 3.0 * (i * 7 % n) as f64 / n as f64)
+            .collect();
+        let b: Vec<_> = bl
+            .iter()
+            .zip(&bp)
+            .map(|(&l, &p)| spherical_to_cartesian(l, p))
+            .collect();
+        let (al, ap) = (1.0, 0.1);
+        let a = [1.0, 0.1, 0.2];
+
+        let mut out = vec![0.0; n];
+        sep_spherical_coords(al, ap, &bl, &bp, &mut out);
+        for i in 0..n {
+            assert_eq!(out[i], scalar::sep_spherical_coords(al, ap, bl[i], bp[i]));
+        }
+        sep_vectors(a, &b, &mut out);
+        for i in 0..n {
+            assert_eq!(out[i], scalar::sep_vectors(a, b[i]));
+        }
+        position_angle_vectors(a, &b, &mut out);
+        for i in 0..n {
+            assert_eq!(out[i], scalar::position_angle_vectors(a, b[i]));
+        }
+        position_angle_vectors([0.0; 3], &b, &mut out);
+        for i in 0..n {
+            assert_eq!(out[i], scalar::position_angle_vectors([0.0; 3], b[i]));
+        }
+        position_angle_spherical_coords(al, ap, &bl, &bp, &mut out);
+        for i in 0..n {
+            assert_eq!(
+                out[i],
+                scalar::position_angle_spherical_coords(al, ap, bl[i], bp[i])
+            );
+        }
+    }
+}
@@ -0,0 +1,197 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Code to calculate angular separations.
+
+pub mod batch;
+
+use crate::math;
+
+/// Angular separation between two sets of spherical coordinates. (`eraSeps`)
+///
+/// Given:
+/// * `a_long`: first longitude (radians)
+/// * `a_lat`: first latitude (radians)
+/// * `b_long`: second longitude (radians)
+/// * `b_lat`: second latitude (radians)
+///
+/// Returned:
+/// * angular separation (radians)
+///
+pub fn sep_spherical_coords(a_long: f64, a_lat: f64, b_long: f64, b_lat: f64) -> f64 {
+    use crate::transform::spherical_to_cartesian;
+
+    let ac = spherical_to_cartesian(a_long, a_lat);
+    let bc = spherical_to_cartesian(b_long, b_lat);
+
+    /* Angle between the vectors. */
+    sep_vectors(ac, bc)
+}
+
+/// Angular separation between two p-vectors. (`eraSepp`)
+///
+/// Given:
+/// * `a`: first p-vector (not necessarily unit length)
+/// * `b`: second p-vector (not necessarily unit length)
+///
+/// Returned:
+/// * angular separation (radians, always positive)
+///
+/// Notes:
+///
+/// * The angular separation is most simply formulated in terms of
+///   scalar product.  However, this gives poor accuracy for angles
+///   near zero and pi.  The present algorithm uses both cross product
+///   and dot product, to deliver full accuracy whatever the size of
+///   the angle.
+///
+pub fn sep_vectors(a: [f64; 3], b: [f64; 3]) -> f64 {
+    use crate::vectors_and_matrices;
+
+    /* Sine of angle between the vectors, multiplied by the two moduli. */
+    let axb = vectors_and_matrices::outer_product(a, b);
+    let ss = vectors_and_matrices::modulus(axb);
+
+    /* Cosine of the angle, multiplied by the two moduli. */
+    let cs = vectors_and_matrices::inner_product(a, b);
+
+    /* The angle. */
+    if (ss != 0.0) || (cs != 0.0) {
+        math::atan2(ss, cs)
+    } else {
+        0.0
+    }
+}
+
+/// Position-angle from two p-vectors. (`eraPap`)
+///
+/// Given:
+/// * `a`: direction of reference point
+/// * `b`: direction of point whose PA is required
+///
+/// Returned:
+/// * position angle of `b` with respect to `a` (radians)
+///
+/// # Notes:
+///
+/// 1) The result is the position angle, in radians, of direction `b` with
+///    respect to direction `a`.  It is in the range -pi to +pi.  The sense is
+///    such that if `b` is a small distance "north" of `a` the position angle is
+///    approximately zero, and if `b` is a small distance "east" of `a` the
+///    position angle is approximately +pi/2.
+///
+/// 2) The vectors `a` and `b` need not be of unit length.
+///
+/// 3) Zero is returned if the two directions are the same or if either vector
+///    is null.
+///
+/// 4) If vector `a` is at a pole, the result is ill-defined.
+///
+pub fn position_angle_vectors(a: [f64; 3], b: [f64; 3]) -> f64 {
+    PapReference::new(a).position_angle(b)
+}
+
+/// The terms of [`position_angle_vectors`] that depend only on `a`.
+pub(crate) struct PapReference {
+    am: f64,
+    au: [f64; 3],
+    eta: [f64; 3],
+    xi: [f64; 3],
+}
+
+impl PapReference {
+    pub(crate) fn new(a: [f64; 3]) -> Self {
+        use crate::vectors_and_matrices;
+
+        /* Modulus and direction of the a vector. */
+        let (am, au) = vectors_and_matrices::modulus_and_unit_vector(a);
+
+        /* The "north" axis tangential from a (arbitrary length). */
+        let xa = a[0];
+        let ya = a[1];
+        let za = a[2];
+        let eta = [-xa * za, -ya * za, xa * xa + ya * ya];
+
+        /* The "east" axis tangential from a (same length). */
+        let xi = vectors_and_matrices::outer_product(eta, au);
+
+        Self { am, au, eta, xi }
+    }
+
+    pub(crate) fn position_angle(&self, b: [f64; 3]) -> f64 {
+        use crate::vectors_and_matrices;
+
+        let au = self.au;
+
+        /* Modulus of the b vector. */
+        let bm = vectors_and_matrices::modulus(b);
+
+        /* Deal with the case of a null vector. */
+        let (st, ct) = if self.am == 0.0 || bm == 0.0 {
+            (0.0, 1.0)
+        } else {
+            /* The vector from a to b. */
+            let a2b = [b[0] - au[0], b[1] - au[1], b[2] - au[2]];
+
+            /* Resolve into components along the north and east axes. */
+            let st = vectors_and_matrices::inner_product(a2b, self.xi);
+            let ct = vectors_and_matrices::inner_product(a2b, self.eta);
+
+            /* Deal with degenerate cases. */
+            if st == 0.0 && ct == 0.0 {
+                (st, 1.0)
+            } else {
+                (st, ct)
+            }
+        };
+
+        /* Position angle. */
+        math::atan2(st, ct)
+    }
+}
+
+/// Position-angle from spherical coordinates. (`eraPas`)
+///
+/// Given:
+/// * `al`: longitude of point A (e.g. RA) in radians
+/// * `ap`: latitude of point A (e.g. Dec) in radians
+/// * `bl`: longitude of point B
+/// * `bp`: latitude of point B
+///
+/// Returned:
+/// * position angle of B with respect to A
+///
+/// # Notes:
+///
+/// 1) The result is the bearing (position angle), in radians, of point B with
+///    respect to point A.  It is in the range -pi to +pi.  The sense is such
+///    that if B is a small distance "east" of point A, the bearing is
+///    approximately +pi/2.
+///
+/// 2) Zero is returned if the two points are coincident.
+///
+pub fn position_angle_spherical_coords(al: f64, ap: f64, bl: f64, bp: f64) -> f64 {
+    let (sap, cap) = math::sin_cos(ap);
+    position_angle_spherical_coords_sc(al, sap, cap, bl, bp)
+}
+
+/// [`position_angle_spherical_coords`] given the sine and cosine of `ap`.
+pub(crate) fn position_angle_spherical_coords_sc(
+    al: f64,
+    sap: f64,
+    cap: f64,
+    bl: f64,
+    bp: f64,
+) -> f64 {
+    let dl = bl - al;
+    let (sdl, cdl) = math::sin_cos(dl);
+    let (sbp, cbp) = math::sin_cos(bp);
+    let y = sdl * cbp;
+    let x = sbp * cap - cbp * sap * cdl;
+    if x != 0.0 || y != 0.0 {
+        math::atan2(y, x)
+    } else {
+        0.0
+    }
+}
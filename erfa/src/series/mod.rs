@@ -0,0 +1,212 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Evaluation of Poisson series: a polynomial in time plus trigonometric terms
+//! whose arguments are (usually integer) combinations of the fundamental
+//! arguments, and whose amplitudes may be multiplied by powers of time.
+//!
+//! This is the engine behind [`nut00a`](crate::prenut::nut00a),
+//! [`S06`](crate::time::S06) and
+//! [`position_velocity_00`](crate::earth::position_velocity_00), each of which
+//! implements [`PoissonTerm`] for its own coefficient tables. The same engine
+//! evaluates user-supplied tables, such as the IERS Conventions series for the
+//! CIP X and Y (`tab5.2a.txt`); see `Series`.
+//!
+//! Terms are summed with an [`Accumulator`]. The built-in models use
+//! [`Sequential`] summation, in ERFA's order, so that they reproduce ERFA; a
+//! `Series` uses [`Compensated`] summation.
+
+#[cfg(feature = "alloc")]
+mod table;
+#[cfg(feature = "alloc")]
+pub use table::Series;
+
+use crate::real::Real;
+
+/// A running sum of series terms.
+pub trait Accumulator<R = f64> {
+    /// Start a sum at `initial`.
+    fn new(initial: R) -> Self;
+
+    /// Add `x` to the sum.
+    fn add(&mut self, x: R);
+
+    /// The sum so far.
+    fn sum(&self) -> R;
+}
+
+/// Plain floating-point summation, in the order that the terms are given.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sequential<R = f64>(R);
+
+impl<R: Real> Accumulator<R> for Sequential<R> {
+    fn new(initial: R) -> Self {
+        Self(initial)
+    }
+
+    fn add(&mut self, x: R) {
+        self.0 = self.0 + x;
+    }
+
+    fn sum(&self) -> R {
+        self.0
+    }
+}
+
+/// Compensated (Kahan-Babuska-Neumaier) summation: the rounding error of each
+/// addition is carried separately, so that the result is nearly independent of
+/// the number and order of the terms.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Compensated {
+    sum: f64,
+    compensation: f64,
+}
+
+impl Accumulator for Compensated {
+    fn new(initial: f64) -> Self {
+        Self {
+            sum: initial,
+            compensation: 0.0,
+        }
+    }
+
+    fn add(&mut self, x: f64) {
+        let t = self.sum + x;
+        if self.sum.abs() >= x.abs() {
+            self.compensation += (self.sum - t) + x;
+        } else {
+            self.compensation += (x - t) + self.sum;
+        }
+        self.sum = t;
+    }
+
+    fn sum(&self) -> f64 {
+        self.sum + self.compensation
+    }
+}
+
+/// One term of a Poisson series, contributing to `N` quantities at once (e.g.
+/// both nutation components, or a position and its rate). The series may be
+/// evaluated with any [`Real`] type `R` that the term supports.
+pub trait PoissonTerm<const N: usize, R = f64> {
+    /// The argument of the term (radians), given `t` and the arguments of the
+    /// series (e.g. [`FundamentalArguments::to_array`]).
+    ///
+    /// [`FundamentalArguments::to_array`]:
+    ///     crate::fundamental_argument::FundamentalArguments::to_array
+    fn argument(&self, t: R, args: &[R]) -> R;
+
+    /// The contributions of the term to each quantity, given `t` and the sine
+    /// and cosine of its argument.
+    fn contributions(&self, t: R, sin: R, cos: R) -> [R; N];
+}
+
+impl<const N: usize, R, T: PoissonTerm<N, R>> PoissonTerm<N, R> for &T {
+    fn argument(&self, t: R, args: &[R]) -> R {
+        (*self).argument(t, args)
+    }
+
+    fn contributions(&self, t: R, sin: R, cos: R) -> [R; N] {
+        (*self).contributions(t, sin, cos)
+    }
+}
+
+/// A term whose argument is an integer combination of the series arguments,
+/// with sine and cosine amplitudes: `sin * sin(arg) + cos * cos(arg)`, where
+/// `arg = sum(multipliers[i] * args[i])`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Term<M> {
+    /// Multipliers of the series arguments.
+    pub multipliers: M,
+    /// Sine amplitude.
+    pub sin: f64,
+    /// Cosine amplitude.
+    pub cos: f64,
+}
+
+impl<M: AsRef<[i32]>, R: Real> PoissonTerm<1, R> for Term<M> {
+    fn argument(&self, _t: R, args: &[R]) -> R {
+        self.multipliers
+            .as_ref()
+            .iter()
+            .copied()
+            .zip(args.iter().copied())
+            .fold(R::from_f64(0.0), |acc, (n, a)| acc + a * f64::from(n))
+    }
+
+    fn contributions(&self, _t: R, sin: R, cos: R) -> [R; 1] {
+        [sin * self.sin + cos * self.cos]
+    }
+}
+
+/// Add the contributions of `terms` to `sums`, in the order given.
+///
+/// Given:
+///  * `terms`: the terms to evaluate
+///  * `t`: the time variable of the series
+///  * `args`: the arguments of the series
+///
+/// Given and returned:
+///  * `sums`: the running sums of each quantity
+pub fn accumulate<const N: usize, R, T, A>(
+    terms: impl IntoIterator<Item = T>,
+    t: R,
+    args: &[R],
+    sums: &mut [A; N],
+) where
+    R: Real,
+    T: PoissonTerm<N, R>,
+    A: Accumulator<R>,
+{
+    for term in terms {
+        let (sin, cos) = term.argument(t, args).sin_cos();
+        for (sum, x) in sums.iter_mut().zip(term.contributions(t, sin, cos)) {
+            sum.add(x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compensated_summation() {
+        let mut sequential = Sequential::new(1.0);
+        let mut compensated = Compensated::new(1.0);
+        for x in [1e100, 1.0, -1e100] {
+            sequential.add(x);
+            compensated.add(x);
+        }
+        assert_eq!(sequential.sum(), 0.0);
+        assert_eq!(compensated.sum(), 2.0);
+
+        let mut compensated = Compensated::new(0.0);
+        for _ in 0..10 {
+            compensated.add(0.1);
+        }
+        assert_eq!(compensated.sum(), 1.0);
+    }
+
+    #[test]
+    fn test_accumulate_terms() {
+        let terms = [
+            Term {
+                multipliers: [1, 0],
+                sin: 2.0,
+                cos: 0.0,
+            },
+            Term {
+                multipliers: [1, -2],
+                sin: 0.0,
+                cos: 3.0,
+            },
+        ];
+        let args = [0.3, 0.2];
+        let mut sums = [Sequential::new(1.0)];
+        accumulate(&terms, 0.0, &args, &mut sums);
+        let expected = 1.0 + 2.0 * 0.3_f64.sin() + 3.0 * (-0.1_f64).cos();
+        assert!((sums[0].sum() - expected).abs() < 1e-15);
+    }
+}
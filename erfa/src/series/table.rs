@@ -0,0 +1,289 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use alloc::{vec, vec::Vec};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+use super::{accumulate, Accumulator, Compensated, Term};
+use crate::{fundamental_argument::FundamentalArguments, ErfaError};
+
+/// A Poisson series held in memory, usually read from an IERS Conventions
+/// table such as `tab5.2a.txt` (the CIP X coordinate).
+///
+/// The series is
+///
+/// `sum_j t^j (p_j + sum_i (a_s)_ij sin(arg_ij) + (a_c)_ij cos(arg_ij))`
+///
+/// where the `p_j` are the polynomial coefficients and `arg_ij` is an integer
+/// combination of the series arguments. The terms are summed with
+/// [`Compensated`] summation. Results are in the units of the table (e.g.
+/// microarcseconds for the IERS tables).
+///
+/// # Table format
+///
+/// [`Series::parse`] reads the layout of the IERS Conventions tables:
+///
+/// * a polynomial line, e.g. `X = -16617. + 2004191898. t - 429782.9 t^2`;
+/// * for each power of t, a header line `j = 0  Number of terms = 1306`,
+///   followed by its terms;
+/// * each term on its own line: an index, the sine and cosine amplitudes,
+///   then the integer multipliers of the arguments.
+///
+/// Any other line (titles, column headings, rules) is ignored. The number of
+/// terms announced by each header is checked, as is the number of multipliers
+/// on each line. With the IERS tables, the arguments are the 14 returned by
+/// [`FundamentalArguments::to_array`], which is what [`Series::evaluate`]
+/// uses.
+///
+/// ```
+/// use erfa::{fundamental_argument::FundamentalArguments, series::Series};
+///
+/// let table = "
+///   X = 100.0 + 2.0 t
+///   j = 0  Number of terms = 1
+///     1   -6844318.44   1328.67   0 0 0 0 1 0 0 0 0 0 0 0 0 0
+/// ";
+/// let series: Series = table.parse().unwrap();
+/// let fa = FundamentalArguments::new(2451545.0, 0.0);
+/// let x = series.evaluate(&fa);
+/// let expected = 100.0 - 6844318.44 * fa.om.sin() + 1328.67 * fa.om.cos();
+/// assert!((x - expected).abs() < 1e-8);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Series {
+    polynomial: Vec<f64>,
+    terms: Vec<Vec<Term<Vec<i32>>>>,
+}
+
+impl Series {
+    /// A series from its polynomial coefficients (in increasing powers of t)
+    /// and its terms, grouped by the power of t that multiplies them.
+    pub fn new(polynomial: Vec<f64>, terms: Vec<Vec<Term<Vec<i32>>>>) -> Self {
+        Self { polynomial, terms }
+    }
+
+    /// Read a series from a file in the IERS table format (see [`Series`]).
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ErfaError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Read a series in the IERS table format (see [`Series`]).
+    ///
+    /// An [`ErfaError::InvalidSeries`] is returned, with the (1-based) line
+    /// number, if the text is not a valid table.
+    pub fn parse(text: &str) -> Result<Self, ErfaError> {
+        let mut polynomial = vec![];
+        let mut terms: Vec<Vec<Term<Vec<i32>>>> = vec![];
+        let mut expected: Vec<(usize, usize, usize)> = vec![];
+        let mut power = None;
+        let mut n_multipliers = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let invalid = |reason| ErfaError::InvalidSeries {
+                line: i + 1,
+                reason,
+            };
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens.as_slice() {
+                /* "j = 0  Number of terms = 1306" */
+                ["j", "=", j, rest @ ..] => {
+                    let j: usize = j.parse().map_err(|_| invalid("bad power of t"))?;
+                    if terms.len() <= j {
+                        terms.resize(j + 1, vec![]);
+                    }
+                    if let [.., "=", n] = rest {
+                        let n = n.parse().map_err(|_| invalid("bad number of terms"))?;
+                        expected.push((j, n, i + 1));
+                    }
+                    power = Some(j);
+                }
+
+                /* "X = -16617. + 2004191898. t - 429782.9 t^2 ..." */
+                [_, "=", rest @ ..] if rest.iter().any(|t| t.starts_with('t')) => {
+                    polynomial = parse_polynomial(rest).ok_or_else(|| invalid("bad polynomial"))?;
+                }
+
+                /* "1   -6844318.44   1328.67   0 0 0 0 1 ..." */
+                [index, sin, cos, multipliers @ ..] if index.parse::<usize>().is_ok() => {
+                    let j = power.ok_or_else(|| invalid("term before any 'j = ' line"))?;
+                    let sin = sin.parse().map_err(|_| invalid("bad sine amplitude"))?;
+                    let cos = cos.parse().map_err(|_| invalid("bad cosine amplitude"))?;
+                    let multipliers = multipliers
+                        .iter()
+                        .map(|n| n.parse())
+                        .collect::<Result<Vec<i32>, _>>()
+                        .map_err(|_| invalid("bad argument multiplier"))?;
+                    if *n_multipliers.get_or_insert(multipliers.len()) != multipliers.len() {
+                        return Err(invalid("wrong number of argument multipliers"));
+                    }
+                    terms[j].push(Term {
+                        multipliers,
+                        sin,
+                        cos,
+                    });
+                }
+
+                _ => (),
+            }
+        }
+
+        for (j, n, line) in expected {
+            if terms[j].len() != n {
+                return Err(ErfaError::InvalidSeries {
+                    line,
+                    reason: "wrong number of terms",
+                });
+            }
+        }
+
+        Ok(Self { polynomial, terms })
+    }
+
+    /// The polynomial coefficients, in increasing powers of t.
+    pub fn polynomial(&self) -> &[f64] {
+        &self.polynomial
+    }
+
+    /// The terms multiplied by t^`power`.
+    pub fn terms(&self, power: usize) -> &[Term<Vec<i32>>] {
+        self.terms.get(power).map_or(&[], |t| t.as_slice())
+    }
+
+    /// Evaluate the series for the given fundamental arguments, using `fa.t`
+    /// as t and [`FundamentalArguments::to_array`] as the series arguments
+    /// (the convention of the IERS tables).
+    pub fn evaluate(&self, fa: &FundamentalArguments) -> f64 {
+        self.evaluate_args(fa.t, &fa.to_array())
+    }
+
+    /// Evaluate the series at time `t` for the series arguments `args`.
+    pub fn evaluate_args(&self, t: f64, args: &[f64]) -> f64 {
+        let n = self.polynomial.len().max(self.terms.len());
+        (0..n).rev().fold(0.0, |acc, j| {
+            let mut w = [Compensated::new(
+                self.polynomial.get(j).copied().unwrap_or(0.0),
+            )];
+            accumulate(self.terms(j), t, args, &mut w);
+            acc * t + w[0].sum()
+        })
+    }
+}
+
+impl FromStr for Series {
+    type Err = ErfaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Parse polynomial tokens such as `["-16617.", "+", "2004191898.", "t", "-",
+/// "429782.9", "t^2"]` into coefficients in increasing powers of t.
+fn parse_polynomial(tokens: &[&str]) -> Option<Vec<f64>> {
+    let mut coeffs = vec![];
+    let mut sign = 1.0;
+    let mut tokens = tokens.iter().peekable();
+    while let Some(token) = tokens.next() {
+        match *token {
+            "+" => sign = 1.0,
+            "-" => sign = -1.0,
+            token => {
+                /* Stop at anything that isn't part of the polynomial, such as */
+                /* a trailing unit. */
+                let Ok(value) = token.parse::<f64>() else {
+                    break;
+                };
+                let power = match tokens.peek() {
+                    Some(&&"t") => {
+                        tokens.next();
+                        1
+                    }
+                    Some(t) if t.starts_with("t^") => {
+                        let power = t[2..].parse().ok()?;
+                        tokens.next();
+                        power
+                    }
+                    _ => 0,
+                };
+                if coeffs.len() <= power {
+                    coeffs.resize(power + 1, 0.0);
+                }
+                coeffs[power] = sign * value;
+                sign = 1.0;
+            }
+        }
+    }
+    (!coeffs.is_empty()).then_some(coeffs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE: &str = "
+  Table 5.2a (excerpt)
+  X = -16617. + 2004191898. t - 429782.9 t^2 - 198618.34 t^3 + 7.578 t^4 + 5.9285 t^5
+
+  j = 0  Number of terms = 2
+  ----------------------------------------------------------------------
+      i    (a_{s,0})_i   (a_{c,0})_i    l  l'  F  D Om L_Me L_Ve L_E L_Ma L_J L_Sa L_U L_Ne p_A
+  ----------------------------------------------------------------------
+      1    -6844318.44       1328.67    0  0  0  0  1  0  0  0  0  0  0  0  0  0
+      2     -523908.04       -544.75    0  0  2 -2  2  0  0  0  0  0  0  0  0  0
+
+  j = 1  Number of terms = 1
+      3     -3328.48     205833.15      0  0  0  0  1  0  0  0  0  0  0  0  0  0
+";
+
+    #[test]
+    fn test_parse_table() {
+        let series: Series = TABLE.parse().unwrap();
+        assert_eq!(
+            series.polynomial(),
+            [-16617.0, 2004191898.0, -429782.9, -198618.34, 7.578, 5.9285]
+        );
+        assert_eq!(series.terms(0).len(), 2);
+        assert_eq!(series.terms(1).len(), 1);
+        assert!(series.terms(2).is_empty());
+        assert_eq!(series.terms(0)[1].multipliers[2..5], [2, -2, 2]);
+        assert_eq!(series.terms(1)[0].cos, 205833.15);
+
+        let fa = FundamentalArguments::new(2400000.5, 53736.0);
+        let t = fa.t;
+        let a2 = 2.0 * fa.f - 2.0 * fa.d + 2.0 * fa.om;
+        let expected = -16617.0
+            + 2004191898.0 * t
+            + -429782.9 * t * t
+            + -198618.34 * t.powi(3)
+            + 7.578 * t.powi(4)
+            + 5.9285 * t.powi(5)
+            + -6844318.44 * fa.om.sin()
+            + 1328.67 * fa.om.cos()
+            + -523908.04 * a2.sin()
+            + -544.75 * a2.cos()
+            + t * (-3328.48 * fa.om.sin() + 205833.15 * fa.om.cos());
+        assert!((series.evaluate(&fa) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let line = |text: &str| match Series::parse(text) {
+            Err(ErfaError::InvalidSeries { line, .. }) => line,
+            other => panic!("unexpected {other:?}"),
+        };
+        assert_eq!(line("X = 1.0 + 2.0 t\n 1 1.0 2.0 0 1"), 2);
+        assert_eq!(line("j = 0\n 1 1.0 2.0 0 1\n 2 1.0 2.0 0 x"), 3);
+        assert_eq!(line("j = 0\n 1 1.0 2.0 0 1\n 2 1.0 2.0 0 1 1"), 3);
+        assert_eq!(
+            line("X = 1.0 t\nj = 0 Number of terms = 2\n 1 1.0 2.0 0 1"),
+            2
+        );
+        #[cfg(feature = "std")]
+        assert!(Series::from_file("/this/file/does/not/exist").is_err());
+    }
+}
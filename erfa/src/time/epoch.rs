@@ -0,0 +1,369 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A two-part Julian Date tagged with its time scale.
+
+use core::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::{constants::*, math};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A time scale that can tag an [`Epoch`]. This trait is sealed; the
+/// available scales are [`TAI`], [`TT`], [`TDB`] and [`UT1`].
+pub trait TimeScale: sealed::Sealed + Clone + Copy + Debug {
+    /// The conventional abbreviation of the time scale, e.g. "TT".
+    const NAME: &'static str;
+}
+
+macro_rules! time_scale {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[allow(clippy::upper_case_acronyms)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name;
+
+        impl sealed::Sealed for $name {}
+
+        impl TimeScale for $name {
+            const NAME: &'static str = stringify!($name);
+        }
+    };
+}
+
+time_scale!(
+    /// International Atomic Time.
+    TAI
+);
+time_scale!(
+    /// Terrestrial Time.
+    TT
+);
+time_scale!(
+    /// Barycentric Dynamical Time.
+    TDB
+);
+time_scale!(
+    /// Universal Time, UT1.
+    UT1
+);
+
+/// A Julian Date, held in two parts in the usual ERFA manner, in the time
+/// scale `S`.
+///
+/// The two parts are kept normalised: the first is the Julian Date of the
+/// preceding 0h (i.e. it always ends in .5) and the second is the fraction of
+/// a day in the range 0 <= f < 1. This is the "date and time" apportionment
+/// recommended by ERFA; it preserves precision under arithmetic and makes
+/// equality and ordering well-defined.
+///
+/// Conversions between scales that need an externally-supplied quantity (e.g.
+/// UT1-TAI or Delta T) take it as an argument. UTC isn't provided, as it
+/// requires a table of leap seconds.
+#[derive(Clone, Copy, Debug)]
+pub struct Epoch<S: TimeScale> {
+    jd1: f64,
+    jd2: f64,
+    scale: PhantomData<S>,
+}
+
+impl<S: TimeScale> Epoch<S> {
+    /// The epoch J2000.0 (JD 2451545.0) in the scale `S`.
+    pub const J2000: Self = Self {
+        jd1: ERFA_DJ00 - 0.5,
+        jd2: 0.5,
+        scale: PhantomData,
+    };
+
+    /// Create an epoch from a two-part Julian Date, apportioned in any
+    /// convenient way between `date1` and `date2`.
+    pub fn new(date1: f64, date2: f64) -> Self {
+        let (jd1, jd2) = normalise(date1, date2);
+        Self {
+            jd1,
+            jd2,
+            scale: PhantomData,
+        }
+    }
+
+    /// Create an epoch from a single-part Julian Date.
+    pub fn from_jd(jd: f64) -> Self {
+        Self::new(jd, 0.0)
+    }
+
+    /// Create an epoch from a Modified Julian Date.
+    pub fn from_mjd(mjd: f64) -> Self {
+        Self::new(ERFA_DJM0, mjd)
+    }
+
+    /// Create an epoch from a Julian Epoch (e.g. 2000.0).
+    pub fn from_julian_epoch(epj: f64) -> Self {
+        let (djm0, djm) = super::julian_epoch_to_date(epj);
+        Self::new(djm0, djm)
+    }
+
+    /// The two parts of the Julian Date, suitable for passing to the untyped
+    /// functions.
+    pub fn parts(self) -> (f64, f64) {
+        (self.jd1, self.jd2)
+    }
+
+    /// The Julian Date as a single number. This loses precision.
+    pub fn jd(self) -> f64 {
+        self.jd1 + self.jd2
+    }
+
+    /// The Modified Julian Date.
+    pub fn mjd(self) -> f64 {
+        (self.jd1 - ERFA_DJM0) + self.jd2
+    }
+
+    /// The Julian Epoch.
+    pub fn julian_epoch(self) -> f64 {
+        super::julian_date_to_epoch(self.jd1, self.jd2)
+    }
+
+    /// Advance the epoch by `days` days (which may be negative). Whole days
+    /// are applied to the first part, so precision isn't lost.
+    pub fn add_days(self, days: f64) -> Self {
+        let whole = math::trunc(days);
+        Self::new(self.jd1 + whole, self.jd2 + (days - whole))
+    }
+
+    /// Advance the epoch by `seconds` seconds (which may be negative).
+    pub fn add_seconds(self, seconds: f64) -> Self {
+        let whole = math::trunc(seconds / ERFA_DAYSEC);
+        let rem = seconds - whole * ERFA_DAYSEC;
+        Self::new(self.jd1 + whole, self.jd2 + rem / ERFA_DAYSEC)
+    }
+
+    /// The interval `self - other` in days.
+    pub fn days_since(self, other: Self) -> f64 {
+        (self.jd1 - other.jd1) + (self.jd2 - other.jd2)
+    }
+
+    /// The interval `self - other` in seconds.
+    pub fn seconds_since(self, other: Self) -> f64 {
+        (self.jd1 - other.jd1) * ERFA_DAYSEC + (self.jd2 - other.jd2) * ERFA_DAYSEC
+    }
+}
+
+impl Epoch<TAI> {
+    /// Convert to TT. (`eraTaitt`)
+    pub fn to_tt(self) -> Epoch<TT> {
+        let (tt1, tt2) = super::tai_to_tt(self.jd1, self.jd2);
+        Epoch::new(tt1, tt2)
+    }
+
+    /// Convert to UT1, given `dta` (UT1-TAI in seconds). (`eraTaiut1`)
+    pub fn to_ut1(self, dta: f64) -> Epoch<UT1> {
+        let (ut11, ut12) = super::tai_to_ut1(self.jd1, self.jd2, dta);
+        Epoch::new(ut11, ut12)
+    }
+}
+
+impl Epoch<TT> {
+    /// Convert to TAI. (`eraTttai`)
+    pub fn to_tai(self) -> Epoch<TAI> {
+        let (tai1, tai2) = super::tt_to_tai(self.jd1, self.jd2);
+        Epoch::new(tai1, tai2)
+    }
+
+    /// Convert to TDB, given `dtr` (TDB-TT in seconds). (`eraTttdb`)
+    ///
+    /// For most purposes `dtr` can be taken as zero; the difference is at most
+    /// about 1.7 ms.
+    pub fn to_tdb(self, dtr: f64) -> Epoch<TDB> {
+        let (tdb1, tdb2) = super::tt_to_tdb(self.jd1, self.jd2, dtr);
+        Epoch::new(tdb1, tdb2)
+    }
+
+    /// Convert to UT1, given `dt` (TT-UT1 in seconds, i.e. Delta T).
+    /// (`eraTtut1`)
+    pub fn to_ut1(self, dt: f64) -> Epoch<UT1> {
+        let (ut11, ut12) = super::tt_to_ut1(self.jd1, self.jd2, dt);
+        Epoch::new(ut11, ut12)
+    }
+}
+
+impl Epoch<TDB> {
+    /// Convert to TT, given `dtr` (TDB-TT in seconds). (`eraTdbtt`)
+    pub fn to_tt(self, dtr: f64) -> Epoch<TT> {
+        let (tt1, tt2) = super::tdb_to_tt(self.jd1, self.jd2, dtr);
+        Epoch::new(tt1, tt2)
+    }
+}
+
+impl Epoch<UT1> {
+    /// Convert to TT, given `dt` (TT-UT1 in seconds, i.e. Delta T).
+    /// (`eraUt1tt`)
+    pub fn to_tt(self, dt: f64) -> Epoch<TT> {
+        let (tt1, tt2) = super::ut1_to_tt(self.jd1, self.jd2, dt);
+        Epoch::new(tt1, tt2)
+    }
+
+    /// Convert to TAI, given `dta` (UT1-TAI in seconds). (`eraUt1tai`)
+    pub fn to_tai(self, dta: f64) -> Epoch<TAI> {
+        let (tai1, tai2) = super::ut1_to_tai(self.jd1, self.jd2, dta);
+        Epoch::new(tai1, tai2)
+    }
+}
+
+impl<S: TimeScale> PartialEq for Epoch<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S: TimeScale> Eq for Epoch<S> {}
+
+impl<S: TimeScale> PartialOrd for Epoch<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: TimeScale> Ord for Epoch<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.jd1
+            .total_cmp(&other.jd1)
+            .then(self.jd2.total_cmp(&other.jd2))
+    }
+}
+
+impl<S: TimeScale> Hash for Epoch<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.jd1.to_bits().hash(state);
+        self.jd2.to_bits().hash(state);
+    }
+}
+
+impl<S: TimeScale> core::fmt::Display for Epoch<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "JD {} + {} {}", self.jd1, self.jd2, S::NAME)
+    }
+}
+
+/// Re-apportion a two-part Julian Date so that the first part is the Julian
+/// Date of the preceding 0h and the second is the fraction of a day.
+fn normalise(date1: f64, date2: f64) -> (f64, f64) {
+    /* Sum, and the rounding error of the sum (Knuth's two-sum). */
+    let sum = date1 + date2;
+    let bb = sum - date1;
+    let err = (date1 - (sum - bb)) + (date2 - bb);
+
+    /* Split at the preceding 0h. */
+    let mut day = math::floor(sum - 0.5) + 0.5;
+    let mut frac = (sum - day) + err;
+
+    /* The rounding error may have pushed the fraction out of range. */
+    if frac < 0.0 {
+        day -= 1.0;
+        frac += 1.0;
+        /* A tiny negative fraction can round to exactly one day. */
+        if frac >= 1.0 {
+            day += 1.0;
+            frac = 0.0;
+        }
+    } else if frac >= 1.0 {
+        day += 1.0;
+        frac -= 1.0;
+    }
+
+    /* Avoid a negative zero, so that equal epochs compare equal. */
+    (day, frac + 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec};
+
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        let a = Epoch::<TT>::new(2400000.5, 50123.2);
+        let b = Epoch::<TT>::new(2450123.7, 0.0);
+        assert_eq!(a.parts().0, 2450123.5);
+        assert_abs_diff_eq!(a.parts().1, 0.2, epsilon = 1e-9);
+        assert_eq!(b.parts().0, 2450123.5);
+        assert_abs_diff_eq!(a.jd(), b.jd(), epsilon = 1e-9);
+        assert_eq!(Epoch::<TT>::new(2451545.0, 0.0), Epoch::<TT>::J2000);
+        assert_eq!(Epoch::<TT>::new(2451544.0, 1.0), Epoch::<TT>::J2000);
+        assert_eq!(Epoch::<TT>::from_mjd(ERFA_DJM00), Epoch::<TT>::J2000);
+
+        // The fraction stays in range even when a tiny negative fraction rolls
+        // over to the previous day.
+        for date2 in [-1e-20, -1e-10, -0.5, 1.0 - 1e-17, 2.75] {
+            let (_, f) = Epoch::<TT>::new(2451544.5, date2).parts();
+            assert!((0.0..1.0).contains(&f), "{date2}: {f}");
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let t0 = Epoch::<TAI>::J2000;
+        // A nanosecond survives being added to and removed from a JD.
+        let t1 = t0.add_seconds(1e-9);
+        assert!(t1 > t0);
+        // The resolution of the day fraction is ~10 ps.
+        assert_abs_diff_eq!(t1.seconds_since(t0), 1e-9, epsilon = 1e-11);
+
+        let t2 = t0.add_days(36525.0).add_seconds(-0.25);
+        assert_abs_diff_eq!(
+            t2.days_since(t0),
+            36525.0 - 0.25 / ERFA_DAYSEC,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(t0.add_days(36525.0).julian_epoch(), 2100.0, epsilon = 1e-12);
+        assert_eq!(Epoch::<TAI>::from_julian_epoch(2000.0), t0);
+
+        let mut v = vec![t2, t1, t0];
+        v.sort();
+        assert_eq!(v, vec![t0, t1, t2]);
+    }
+
+    #[test]
+    fn test_scale_conversions() {
+        let tai = Epoch::<TAI>::new(2453750.5, 0.892482639);
+        let tt = tai.to_tt();
+        assert_abs_diff_eq!(
+            tt.seconds_since(Epoch::new(2453750.5, 0.892482639)),
+            32.184,
+            epsilon = 1e-9
+        );
+        assert_eq!(tt.to_tai(), tai);
+
+        let tdb = tt.to_tdb(-0.000201);
+        assert_abs_diff_eq!(tdb.to_tt(-0.000201).seconds_since(tt), 0.0, epsilon = 1e-9);
+
+        let ut1 = tt.to_ut1(64.8499);
+        assert_abs_diff_eq!(
+            tt.parts().1 - ut1.parts().1,
+            64.8499 / ERFA_DAYSEC,
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(ut1.to_tt(64.8499).seconds_since(tt), 0.0, epsilon = 1e-9);
+        let ut1 = tai.to_ut1(-32.6659);
+        assert_abs_diff_eq!(
+            ut1.seconds_since(tai.to_tt().to_ut1(64.8499)),
+            0.0,
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(ut1.to_tai(-32.6659).seconds_since(tai), 0.0, epsilon = 1e-9);
+        assert_eq!(
+            tt.to_string(),
+            format!("JD 2453750.5 + {} TT", tt.parts().1)
+        );
+    }
+}
@@ -0,0 +1,469 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! An exact, integer-based epoch.
+
+use core::{
+    marker::PhantomData,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    time::Duration,
+};
+
+use super::{Epoch, TimeScale, TAI, TDB, TT, UT1};
+use crate::{constants::*, ErfaError};
+
+/// The number of attoseconds in a second.
+const ATTOS_PER_SECOND: i128 = 1_000_000_000_000_000_000;
+
+/// The number of attoseconds in a day.
+const ATTOS_PER_DAY: i128 = 86_400 * ATTOS_PER_SECOND;
+
+/// The most whole days an epoch can be from J2000.0 (about 2.7e12 years).
+/// This leaves room for a full Julian Date, in attoseconds, to fit in an
+/// `i128` (whose range is about +/-1.97e15 days).
+const MAX_DAYS: i64 = 1_000_000_000_000_000;
+
+/// TT minus TAI (attoseconds).
+const TTMTAI_ATTOS: i128 = 32_184 * ATTOS_PER_SECOND / 1000;
+
+/// An epoch in the time scale `S`, held exactly as a whole number of days
+/// since J2000.0 (JD 2451545.0) and a whole number of attoseconds into that
+/// day.
+///
+/// Unlike [`Epoch`], arithmetic is exact: adding a [`Duration`] never loses
+/// resolution. Use [`ExactEpoch::to_jd_parts`] to obtain the two-part Julian
+/// Date that the ERFA functions expect; for epochs within about a million
+/// years of J2000.0, the pair is chosen such that
+/// [`ExactEpoch::from_jd_parts`] recovers the original epoch exactly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExactEpoch<S: TimeScale> {
+    days: i64,
+    attoseconds: u128,
+    scale: PhantomData<S>,
+}
+
+impl<S: TimeScale> ExactEpoch<S> {
+    /// The epoch J2000.0 (JD 2451545.0) in the scale `S`.
+    pub const J2000: Self = Self {
+        days: 0,
+        attoseconds: 0,
+        scale: PhantomData,
+    };
+
+    /// Create an epoch `days` days and `attoseconds` attoseconds after
+    /// J2000.0. `attoseconds` may exceed a day; the excess is carried into the
+    /// days.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the epoch is more than about 2.7e12 years from J2000.0.
+    pub fn new(days: i64, attoseconds: u128) -> Self {
+        i128::try_from(attoseconds)
+            .ok()
+            .and_then(|a| (days as i128).checked_mul(ATTOS_PER_DAY)?.checked_add(a))
+            .and_then(Self::from_total)
+            .expect("ExactEpoch out of range")
+    }
+
+    /// Whole days since J2000.0 (may be negative).
+    pub fn days(self) -> i64 {
+        self.days
+    }
+
+    /// Attoseconds into the day, in the range 0 to 86400e18.
+    pub fn attoseconds(self) -> u128 {
+        self.attoseconds
+    }
+
+    /// Create an epoch from a two-part Julian Date, apportioned in any
+    /// convenient way between `date1` and `date2`. Each part is rounded to the
+    /// nearest attosecond separately, and the results added.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ErfaError::InvalidValue`] if either part isn't finite, or if
+    /// the date is too far from J2000.0 to be represented.
+    pub fn from_jd_parts(date1: f64, date2: f64) -> Result<Self, ErfaError> {
+        let invalid = |value| ErfaError::InvalidValue {
+            function: "ExactEpoch::from_jd_parts",
+            value,
+        };
+        let a1 = to_attoseconds(date1, Unit::Day).ok_or_else(|| invalid("date1"))?;
+        let a2 = to_attoseconds(date2, Unit::Day).ok_or_else(|| invalid("date2"))?;
+        a1.checked_add(a2)
+            .and_then(|a| a.checked_sub(ERFA_DJ00 as i128 * ATTOS_PER_DAY))
+            .and_then(Self::from_total)
+            .ok_or_else(|| invalid("date1+date2"))
+    }
+
+    /// The two-part Julian Date of this epoch.
+    ///
+    /// `date1` is the nearest `f64` to the full Julian Date and `date2` the
+    /// (small) remainder. Within about a million years of J2000.0, the
+    /// remainder is resolved more finely than an attosecond, so no information
+    /// is lost: passing the pair to [`ExactEpoch::from_jd_parts`] gives back
+    /// the same epoch.
+    pub fn to_jd_parts(self) -> (f64, f64) {
+        let date1 = (ERFA_DJ00 + self.days as f64) + self.attoseconds as f64 / ATTOS_PER_DAY as f64;
+        // Carry whatever `date1` misses in `date2`. `from_total` bounds the
+        // epoch to `MAX_DAYS`, so `date1` in attoseconds fits in an `i128`.
+        let total = self.total();
+        let a1 = to_attoseconds(date1, Unit::Day).unwrap() - ERFA_DJ00 as i128 * ATTOS_PER_DAY;
+        (date1, (total - a1) as f64 / ATTOS_PER_DAY as f64)
+    }
+
+    /// The (inexact) [`Epoch`] equivalent to this epoch.
+    pub fn to_epoch(self) -> Epoch<S> {
+        let (date1, date2) = self.to_jd_parts();
+        Epoch::new(date1, date2)
+    }
+
+    /// The exact interval `self - other` in attoseconds.
+    pub fn attoseconds_since(self, other: Self) -> i128 {
+        self.total() - other.total()
+    }
+
+    /// The interval `self - other` in seconds. This loses resolution.
+    pub fn seconds_since(self, other: Self) -> f64 {
+        let diff = self.attoseconds_since(other);
+        let secs = diff.div_euclid(ATTOS_PER_SECOND);
+        let attos = diff.rem_euclid(ATTOS_PER_SECOND);
+        secs as f64 + attos as f64 / ATTOS_PER_SECOND as f64
+    }
+
+    /// Advance the epoch by `duration`, returning `None` on overflow.
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        duration_attoseconds(duration)
+            .checked_add(self.total())
+            .and_then(Self::from_total)
+    }
+
+    /// Step the epoch back by `duration`, returning `None` on overflow.
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.total()
+            .checked_sub(duration_attoseconds(duration))
+            .and_then(Self::from_total)
+    }
+
+    /// The epoch `attoseconds` later, in the scale `T`.
+    fn offset<T: TimeScale>(self, attoseconds: i128) -> ExactEpoch<T> {
+        self.total()
+            .checked_add(attoseconds)
+            .and_then(ExactEpoch::from_total)
+            .expect("overflow when changing time scale")
+    }
+
+    /// The epoch `seconds` later (rounded to the nearest attosecond), in the
+    /// scale `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seconds` isn't finite.
+    fn offset_seconds<T: TimeScale>(self, seconds: f64) -> ExactEpoch<T> {
+        self.offset(to_attoseconds(seconds, Unit::Second).expect("invalid time scale offset"))
+    }
+
+    /// Attoseconds since J2000.0.
+    fn total(self) -> i128 {
+        self.days as i128 * ATTOS_PER_DAY + self.attoseconds as i128
+    }
+
+    fn from_total(total: i128) -> Option<Self> {
+        let days = total.div_euclid(ATTOS_PER_DAY);
+        if days.unsigned_abs() > MAX_DAYS as u128 {
+            return None;
+        }
+        Some(Self {
+            days: days as i64,
+            attoseconds: total.rem_euclid(ATTOS_PER_DAY) as u128,
+            scale: PhantomData,
+        })
+    }
+}
+
+impl ExactEpoch<TAI> {
+    /// Convert to TT. This is exact.
+    pub fn to_tt(self) -> ExactEpoch<TT> {
+        self.offset(TTMTAI_ATTOS)
+    }
+
+    /// Convert to UT1, given `dta` (UT1-TAI in seconds).
+    pub fn to_ut1(self, dta: f64) -> ExactEpoch<UT1> {
+        self.offset_seconds(dta)
+    }
+}
+
+impl ExactEpoch<TT> {
+    /// Convert to TAI. This is exact.
+    pub fn to_tai(self) -> ExactEpoch<TAI> {
+        self.offset(-TTMTAI_ATTOS)
+    }
+
+    /// Convert to TDB, given `dtr` (TDB-TT in seconds).
+    pub fn to_tdb(self, dtr: f64) -> ExactEpoch<TDB> {
+        self.offset_seconds(dtr)
+    }
+
+    /// Convert to UT1, given `dt` (TT-UT1 in seconds, i.e. Delta T).
+    pub fn to_ut1(self, dt: f64) -> ExactEpoch<UT1> {
+        self.offset_seconds(-dt)
+    }
+}
+
+impl ExactEpoch<TDB> {
+    /// Convert to TT, given `dtr` (TDB-TT in seconds).
+    pub fn to_tt(self, dtr: f64) -> ExactEpoch<TT> {
+        self.offset_seconds(-dtr)
+    }
+}
+
+impl ExactEpoch<UT1> {
+    /// Convert to TT, given `dt` (TT-UT1 in seconds, i.e. Delta T).
+    pub fn to_tt(self, dt: f64) -> ExactEpoch<TT> {
+        self.offset_seconds(dt)
+    }
+
+    /// Convert to TAI, given `dta` (UT1-TAI in seconds).
+    pub fn to_tai(self, dta: f64) -> ExactEpoch<TAI> {
+        self.offset_seconds(-dta)
+    }
+}
+
+impl<S: TimeScale> Add<Duration> for ExactEpoch<S> {
+    type Output = Self;
+
+    fn add(self, rhs: Duration) -> Self {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to epoch")
+    }
+}
+
+impl<S: TimeScale> AddAssign<Duration> for ExactEpoch<S> {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl<S: TimeScale> Sub<Duration> for ExactEpoch<S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Duration) -> Self {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from epoch")
+    }
+}
+
+impl<S: TimeScale> SubAssign<Duration> for ExactEpoch<S> {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl<S: TimeScale> TryFrom<Epoch<S>> for ExactEpoch<S> {
+    type Error = ErfaError;
+
+    fn try_from(epoch: Epoch<S>) -> Result<Self, ErfaError> {
+        let (date1, date2) = epoch.parts();
+        Self::from_jd_parts(date1, date2)
+    }
+}
+
+impl<S: TimeScale> From<ExactEpoch<S>> for Epoch<S> {
+    fn from(epoch: ExactEpoch<S>) -> Self {
+        epoch.to_epoch()
+    }
+}
+
+fn duration_attoseconds(d: Duration) -> i128 {
+    d.as_secs() as i128 * ATTOS_PER_SECOND + d.subsec_nanos() as i128 * 1_000_000_000
+}
+
+/// A unit of time that is a whole number of attoseconds, expressed as
+/// `2^shift * odd`.
+#[derive(Clone, Copy)]
+enum Unit {
+    Second,
+    Day,
+}
+
+impl Unit {
+    /// 1e18 = 2^18 * 5^18 and 86400e18 = 2^25 * 675 * 5^18.
+    fn factors(self) -> (i32, u128) {
+        const FIVE_18: u128 = 3_814_697_265_625;
+        match self {
+            Unit::Second => (18, FIVE_18),
+            Unit::Day => (25, 675 * FIVE_18),
+        }
+    }
+}
+
+/// Convert `x` (in `unit`s) exactly to attoseconds, rounding half to even.
+/// Returns `None` if `x` isn't finite or the result doesn't fit in an `i128`.
+fn to_attoseconds(x: f64, unit: Unit) -> Option<i128> {
+    if !x.is_finite() {
+        return None;
+    }
+    if x == 0.0 {
+        return Some(0);
+    }
+
+    /* Decompose |x| = mant * 2^exp. */
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let frac = bits & ((1 << 52) - 1);
+    let (mant, exp) = if biased == 0 {
+        (frac, -1074)
+    } else {
+        (frac | (1 << 52), biased - 1075)
+    };
+
+    /* |x| in attoseconds = mant * odd * 2^(exp + shift); mant * odd < 2^105. */
+    let (shift, odd) = unit.factors();
+    let m = mant as u128 * odd;
+    let shift = exp + shift;
+    let abs = if shift >= 0 {
+        if shift as u32 >= m.leading_zeros() {
+            return None;
+        }
+        m << shift
+    } else {
+        let s = -shift as u32;
+        if s > 127 {
+            0
+        } else {
+            let q = m >> s;
+            let r = m & ((1 << s) - 1);
+            let half = 1 << (s - 1);
+            if r > half || (r == half && q & 1 == 1) {
+                q + 1
+            } else {
+                q
+            }
+        }
+    };
+
+    let abs = i128::try_from(abs).ok()?;
+    Some(if x.is_sign_negative() { -abs } else { abs })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jd_parts_round_trip() {
+        for (days, attos) in [
+            (0, 0),
+            (0, 1),
+            (-1, ATTOS_PER_DAY as u128 - 1),
+            (2189, 12_345_678_901_234_567_890_123),
+            (-36525, 43_200 * ATTOS_PER_SECOND as u128 + 7),
+            (1_000_000, 999),
+            (-400_000_000, 123_456_789),
+        ] {
+            let e = ExactEpoch::<TT>::new(days, attos);
+            let (date1, date2) = e.to_jd_parts();
+            assert_eq!(ExactEpoch::from_jd_parts(date1, date2).unwrap(), e);
+            assert!((date1 + date2 - (ERFA_DJ00 + days as f64)).abs() <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_from_jd_parts() {
+        let e = ExactEpoch::<TT>::from_jd_parts(2451545.0, 0.5).unwrap();
+        assert_eq!((e.days(), e.attoseconds()), (0, ATTOS_PER_DAY as u128 / 2));
+
+        // Apportionment doesn't matter when the parts are exact.
+        let a = ExactEpoch::<TT>::from_jd_parts(2400000.5, 50123.25).unwrap();
+        let b = ExactEpoch::<TT>::from_jd_parts(2450123.75, 0.0).unwrap();
+        let c = ExactEpoch::<TT>::from_jd_parts(2451545.0, -1421.25).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a, c);
+
+        assert!(ExactEpoch::<TT>::from_jd_parts(f64::NAN, 0.0).is_err());
+        assert!(ExactEpoch::<TT>::from_jd_parts(0.0, f64::INFINITY).is_err());
+        assert!(ExactEpoch::<TT>::from_jd_parts(1e300, 0.0).is_err());
+        assert!(ExactEpoch::<TT>::from_jd_parts(1.5e15, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_range_limits() {
+        // The extreme epochs convert without overflow.
+        let first = ExactEpoch::<TT>::new(-MAX_DAYS, 0);
+        let last = ExactEpoch::<TT>::new(MAX_DAYS, ATTOS_PER_DAY as u128 - 1);
+        assert_eq!(first.to_jd_parts(), (ERFA_DJ00 - 1e15, 0.0));
+        assert_eq!(last.to_jd_parts().0, ERFA_DJ00 + 1e15 + 1.0);
+        assert!(first.checked_sub(Duration::from_nanos(1)).is_none());
+        assert!(last.checked_add(Duration::from_nanos(1)).is_none());
+    }
+
+    #[test]
+    fn test_duration_arithmetic() {
+        let t0 = ExactEpoch::<TAI>::J2000;
+        let t1 = t0 + Duration::from_nanos(1);
+        assert_eq!(t1.attoseconds_since(t0), 1_000_000_000);
+        assert_eq!(t1 - Duration::from_nanos(1), t0);
+
+        // A thousand millisecond steps land exactly on one second.
+        let mut t = t0;
+        for _ in 0..1000 {
+            t += Duration::from_micros(1000);
+        }
+        assert_eq!(t.attoseconds_since(t0), ATTOS_PER_SECOND);
+        assert_eq!(t.seconds_since(t0), 1.0);
+
+        // Crossing day boundaries, forwards and backwards.
+        let t2 = t0 - Duration::from_secs(1);
+        assert_eq!(t2.days(), -1);
+        assert_eq!(
+            t2.attoseconds(),
+            ATTOS_PER_DAY as u128 - ATTOS_PER_SECOND as u128
+        );
+        assert!(t2 < t0);
+        let t3 = t0 + Duration::from_secs(86_400 * 3 + 1);
+        assert_eq!(t3.days(), 3);
+
+        // Overflow is reported rather than wrapping.
+        let mut t = Some(t0);
+        for _ in 0..20 {
+            t = t.and_then(|t| t.checked_add(Duration::MAX));
+        }
+        assert!(t.is_none());
+    }
+
+    #[test]
+    fn test_scale_conversions() {
+        let tai = ExactEpoch::<TAI>::new(3_000, 1);
+        assert_eq!(
+            tai.to_tt().attoseconds_since(ExactEpoch::new(3_000, 1)),
+            TTMTAI_ATTOS
+        );
+        assert_eq!(tai.to_tt().to_tai(), tai);
+        let tt = tai.to_tt();
+        assert_eq!(tt.to_tdb(-0.000201).to_tt(-0.000201), tt);
+        assert_eq!(tt.to_ut1(64.8499).to_tt(64.8499), tt);
+        assert_eq!(tai.to_ut1(-0.6).to_tai(-0.6), tai);
+
+        // Agrees with the inexact epoch to within its resolution.
+        let epoch = Epoch::<TT>::from(tt);
+        let exact = ExactEpoch::try_from(epoch).unwrap();
+        assert!(exact.attoseconds_since(tt).abs() < 20_000_000);
+    }
+
+    #[test]
+    fn test_to_attoseconds() {
+        assert_eq!(to_attoseconds(1.0, Unit::Second), Some(ATTOS_PER_SECOND));
+        assert_eq!(to_attoseconds(-0.5, Unit::Day), Some(-ATTOS_PER_DAY / 2));
+        assert_eq!(to_attoseconds(1e-18, Unit::Second), Some(1));
+        assert_eq!(to_attoseconds(4e-19, Unit::Second), Some(0));
+        assert_eq!(to_attoseconds(f64::MIN_POSITIVE, Unit::Day), Some(0));
+        assert_eq!(to_attoseconds(f64::MAX, Unit::Day), None);
+        // 0.1 isn't exactly representable; the exact value of the f64 is
+        // 0.1000000000000000055511151231257827 s.
+        assert_eq!(
+            to_attoseconds(0.1, Unit::Second),
+            Some(100_000_000_000_000_006)
+        );
+    }
+}
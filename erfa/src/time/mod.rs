@@ -4,10 +4,21 @@
 
 //! Time code.
 
+mod epoch;
+mod exact;
 mod s06;
-pub use s06::S06;
+mod scales;
+pub mod typed;
+pub use epoch::{Epoch, TimeScale, TAI, TDB, TT, UT1};
+pub use exact::ExactEpoch;
+pub use s06::{S06_with_args, S06};
+pub use scales::*;
 
-use crate::constants::*;
+use crate::{
+    constants::*,
+    fundamental_argument::FundamentalArguments,
+    real::{polynomial, Real},
+};
 
 /// Julian Date to Julian Epoch. (`eraEpj`)
 ///
@@ -101,12 +112,46 @@ pub fn julian_epoch_to_date(epj: f64) -> (f64, f64) {
 ///
 /// * Wallace, P.T. & Capitaine, N., 2006, Astron.Astrophys. 459, 981
 ///
-pub fn gst06a(uta: f64, utb: f64, tta: f64, ttb: f64) -> f64 {
+pub fn gst06a<R: Real>(uta: R, utb: R, tta: R, ttb: R) -> R {
+    gst06a_with_args(uta, utb, &FundamentalArguments::new(tta, ttb))
+}
+
+/// Greenwich apparent sidereal time (consistent with IAU 2000 and 2006
+/// resolutions), given the fundamental arguments for TT. See [`gst06a`]; the
+/// results are identical.
+///
+/// Each fundamental argument is evaluated once, rather than once for the
+/// nutation and again for the CIO locator.
+pub fn gst06a_with_args<R: Real>(uta: R, utb: R, fa: &FundamentalArguments<R>) -> R {
     /* Classical nutation x precession x bias matrix, IAU 2000A. */
-    let rnpb = crate::prenut::pn_matrix_06a(tta, ttb);
+    let rnpb = crate::prenut::pn_matrix_06a_with_args(fa);
+
+    /* Greenwich apparent sidereal time. */
+    gst06_with_args(uta, utb, fa, rnpb)
+}
+
+/// Greenwich apparent sidereal time (consistent with IAU 2000 and 2006
+/// resolutions), with the nutation evaluated to a chosen
+/// [`NutationAccuracy`](crate::prenut::NutationAccuracy). With
+/// `NutationAccuracy::Full`, this is [`gst06a`].
+///
+/// The error in the sidereal time is about that of the nutation in longitude
+/// times cos(obliquity); see
+/// [`NutationAccuracy`](crate::prenut::NutationAccuracy).
+pub fn gst06a_with_accuracy(
+    uta: f64,
+    utb: f64,
+    tta: f64,
+    ttb: f64,
+    accuracy: crate::prenut::NutationAccuracy,
+) -> f64 {
+    let fa = FundamentalArguments::new(tta, ttb);
+
+    /* Classical nutation x precession x bias matrix. */
+    let rnpb = crate::prenut::pn_matrix_06a_with_args_accuracy(&fa, accuracy);
 
     /* Greenwich apparent sidereal time. */
-    gst06(uta, utb, tta, ttb, rnpb)
+    gst06_with_args(uta, utb, &fa, rnpb)
 }
 
 /// Greenwich apparent sidereal time, IAU 2006, given the NPB matrix.
@@ -157,9 +202,14 @@ pub fn gst06a(uta: f64, utb: f64, tta: f64, ttb: f64) -> f64 {
 ///
 /// * Wallace, P.T. & Capitaine, N., 2006, Astron.Astrophys. 459, 981
 ///
-pub fn gst06(uta: f64, utb: f64, tta: f64, ttb: f64, rnpb: [[f64; 3]; 3]) -> f64 {
+pub fn gst06<R: Real>(uta: R, utb: R, tta: R, ttb: R, rnpb: [[R; 3]; 3]) -> R {
+    gst06_with_args(uta, utb, &FundamentalArguments::new(tta, ttb), rnpb)
+}
+
+/// [`gst06`] given the fundamental arguments for TT.
+fn gst06_with_args<R: Real>(uta: R, utb: R, fa: &FundamentalArguments<R>, rnpb: [[R; 3]; 3]) -> R {
     let (x, y) = crate::prenut::bpn_to_xy(rnpb);
-    let s = S06(tta, ttb, x, y);
+    let s = S06_with_args(fa, x, y);
     let era = crate::earth::earth_rotation_angle_00(uta, utb);
     let eors = crate::prenut::eors(rnpb, s);
     crate::misc::norm_angle(era - eors)
@@ -212,20 +262,21 @@ pub fn gst06(uta: f64, utb: f64, tta: f64, ttb: f64, rnpb: [[f64; 3]; 3]) -> f64
 /// * Capitaine, N., Wallace, P.T. & Chapront, J., 2005, Astron.Astrophys. 432,
 ///   355
 ///
-pub fn gmst06(uta: f64, utb: f64, tta: f64, ttb: f64) -> f64 {
+pub fn gmst06<R: Real>(uta: R, utb: R, tta: R, ttb: R) -> R {
     /* TT Julian centuries since J2000.0. */
     let t = ((tta - ERFA_DJ00) + ttb) / ERFA_DJC;
 
     /* Greenwich mean sidereal time, IAU 2006. */
     #[rustfmt::skip]
     let gmst = crate::misc::norm_angle(crate::earth::earth_rotation_angle_00(uta, utb) +
-                  (    0.014506     +
-                  (  4612.156534    +
-                  (     1.3915817   +
-                  (    -0.00000044  +
-                  (    -0.000029956 +
-                  (    -0.0000000368 )
-          * t) * t) * t) * t) * t) * ERFA_DAS2R);
+        polynomial(t, &[
+               0.014506,
+            4612.156534,
+               1.3915817,
+              -0.00000044,
+              -0.000029956,
+              -0.0000000368,
+        ]) * ERFA_DAS2R);
 
     gmst
 }
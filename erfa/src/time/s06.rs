@@ -2,7 +2,12 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::{constants::*, fundamental_argument::*};
+use crate::{
+    constants::*,
+    fundamental_argument::FundamentalArguments,
+    real::Real,
+    series::{accumulate, Accumulator, Sequential, Term},
+};
 
 /// The CIO locator s, positioning the Celestial Intermediate Origin on the
 /// equator of the Celestial Intermediate Pole, given the CIP's X,Y coordinates.
@@ -59,86 +64,54 @@ use crate::{constants::*, fundamental_argument::*};
 ///   Technical Note No. 32, BKG
 ///
 #[allow(non_snake_case)]
-pub fn S06(date1: f64, date2: f64, x: f64, y: f64) -> f64 {
+pub fn S06<R: Real>(date1: R, date2: R, x: R, y: R) -> R {
+    S06_with_args(&FundamentalArguments::new(date1, date2), x, y)
+}
+
+/// The CIO locator s, given precomputed fundamental arguments and the CIP's
+/// X,Y coordinates. See [`S06`]; the results are identical.
+#[allow(non_snake_case)]
+pub fn S06_with_args<R: Real>(args: &FundamentalArguments<R>, x: R, y: R) -> R {
     /* Interval between fundamental epoch J2000.0 and current date (JC). */
-    let t = (date1 - ERFA_DJ00 + date2) / ERFA_DJC;
+    let t = args.t;
 
     /* Fundamental Arguments (from IERS Conventions 2003) */
-    let fa: [f64; 8] = [
+    #[rustfmt::skip]
+    let fa: [R; 8] = [
         /* Mean anomaly of the Moon. */
-        l03(t),
+        args.l,
         /* Mean anomaly of the Sun. */
-        lp03(t),
+        args.lp,
         /* Mean longitude of the Moon minus that of the ascending node. */
-        f03(t),
+        args.f,
         /* Mean elongation of the Moon from the Sun. */
-        d03(t),
+        args.d,
         /* Mean longitude of the ascending node of the Moon. */
-        om03(t),
+        args.om,
         /* Mean longitude of Venus. */
-        ve03(t),
+        args.ve,
         /* Mean longitude of Earth. */
-        e03(t),
+        args.e,
         /* General precession in longitude. */
-        pa03(t),
+        args.pa,
     ];
 
     /* Evaluate s. */
-    let mut w0 = SP[0];
-    let mut w1 = SP[1];
-    let mut w2 = SP[2];
-    let mut w3 = SP[3];
-    let mut w4 = SP[4];
+    let mut w0 = [Sequential::new(R::from_f64(SP[0]))];
+    let mut w1 = [Sequential::new(R::from_f64(SP[1]))];
+    let mut w2 = [Sequential::new(R::from_f64(SP[2]))];
+    let mut w3 = [Sequential::new(R::from_f64(SP[3]))];
+    let mut w4 = [Sequential::new(R::from_f64(SP[4]))];
     let w5 = SP[5];
 
-    for s0 in S0.iter().rev() {
-        let a = s0
-            .nfa
-            .iter()
-            .copied()
-            .zip(fa.iter().copied())
-            .fold(0.0, |acc, (nfa, fa)| acc + f64::from(nfa) * fa);
-        w0 += s0.s * a.sin() + s0.c * a.cos();
-    }
-    for s1 in S1.iter().rev() {
-        let a = s1
-            .nfa
-            .iter()
-            .copied()
-            .zip(fa.iter().copied())
-            .fold(0.0, |acc, (nfa, fa)| acc + f64::from(nfa) * fa);
-        w1 += s1.s * a.sin() + s1.c * a.cos();
-    }
-    for s2 in S2.iter().rev() {
-        let a = s2
-            .nfa
-            .iter()
-            .copied()
-            .zip(fa.iter().copied())
-            // .fold(0.0, |acc, (nfa, fa)| acc + f64::from(nfa) + fa);
-            .fold(0.0, |acc, (nfa, fa)| acc + f64::from(nfa) * fa);
-        w2 += s2.s * a.sin() + s2.c * a.cos();
-    }
-    for s3 in S3.iter().rev() {
-        let a = s3
-            .nfa
-            .iter()
-            .copied()
-            .zip(fa.iter().copied())
-            .fold(0.0, |acc, (nfa, fa)| acc + f64::from(nfa) * fa);
-        w3 += s3.s * a.sin() + s3.c * a.cos();
-    }
-    for s4 in S4.iter().rev() {
-        let a = s4
-            .nfa
-            .iter()
-            .copied()
-            .zip(fa.iter().copied())
-            .fold(0.0, |acc, (nfa, fa)| acc + f64::from(nfa) * fa);
-        w4 += s4.s * a.sin() + s4.c * a.cos();
-    }
+    accumulate(S0.iter().rev(), t, &fa, &mut w0);
+    accumulate(S1.iter().rev(), t, &fa, &mut w1);
+    accumulate(S2.iter().rev(), t, &fa, &mut w2);
+    accumulate(S3.iter().rev(), t, &fa, &mut w3);
+    accumulate(S4.iter().rev(), t, &fa, &mut w4);
+    let [w0, w1, w2, w3, w4] = [w0, w1, w2, w3, w4].map(|[w]| w.sum());
 
-    (w0 + (w1 + (w2 + (w3 + (w4 + w5 * t) * t) * t) * t) * t) * ERFA_DAS2R - x * y / 2.0
+    (w0 + (w1 + (w2 + (w3 + (w4 + t * w5) * t) * t) * t) * t) * ERFA_DAS2R - x * y / 2.0
 }
 
 /* Polynomial coefficients */
@@ -154,359 +127,353 @@ const SP: [f64; 6] = [
 /* --------------------- */
 /* The series for s+XY/2 */
 /* --------------------- */
-struct Term {
-    /// coefficients of l,l',F,D,Om,LVe,LE,pA
-    nfa: [i32; 8],
-    /// sine coefficients
-    s: f64,
-    /// cosine coefficients
-    c: f64,
-}
+
+/* Multipliers of l,l',F,D,Om,LVe,LE,pA, then sine and cosine coefficients. */
 
 /* Terms of order t^0 */
-const S0: [Term; 33] = [
+const S0: [Term<[i32; 8]>; 33] = [
     Term {
-        nfa: [0, 0, 0, 0, 1, 0, 0, 0],
-        s: -2640.73e-6,
-        c: 0.39e-6,
+        multipliers: [0, 0, 0, 0, 1, 0, 0, 0],
+        sin: -2640.73e-6,
+        cos: 0.39e-6,
     },
     Term {
-        nfa: [0, 0, 0, 0, 2, 0, 0, 0],
-        s: -63.53e-6,
-        c: 0.02e-6,
+        multipliers: [0, 0, 0, 0, 2, 0, 0, 0],
+        sin: -63.53e-6,
+        cos: 0.02e-6,
     },
     Term {
-        nfa: [0, 0, 2, -2, 3, 0, 0, 0],
-        s: -11.75e-6,
-        c: -0.01e-6,
+        multipliers: [0, 0, 2, -2, 3, 0, 0, 0],
+        sin: -11.75e-6,
+        cos: -0.01e-6,
     },
     Term {
-        nfa: [0, 0, 2, -2, 1, 0, 0, 0],
-        s: -11.21e-6,
-        c: -0.01e-6,
+        multipliers: [0, 0, 2, -2, 1, 0, 0, 0],
+        sin: -11.21e-6,
+        cos: -0.01e-6,
     },
     Term {
-        nfa: [0, 0, 2, -2, 2, 0, 0, 0],
-        s: 4.57e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, -2, 2, 0, 0, 0],
+        sin: 4.57e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 2, 0, 3, 0, 0, 0],
-        s: -2.02e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, 0, 3, 0, 0, 0],
+        sin: -2.02e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 2, 0, 1, 0, 0, 0],
-        s: -1.98e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, 0, 1, 0, 0, 0],
+        sin: -1.98e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 0, 0, 3, 0, 0, 0],
-        s: 1.72e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 0, 0, 3, 0, 0, 0],
+        sin: 1.72e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 1, 0, 0, 1, 0, 0, 0],
-        s: 1.41e-6,
-        c: 0.01e-6,
+        multipliers: [0, 1, 0, 0, 1, 0, 0, 0],
+        sin: 1.41e-6,
+        cos: 0.01e-6,
     },
     Term {
-        nfa: [0, 1, 0, 0, -1, 0, 0, 0],
-        s: 1.26e-6,
-        c: 0.01e-6,
+        multipliers: [0, 1, 0, 0, -1, 0, 0, 0],
+        sin: 1.26e-6,
+        cos: 0.01e-6,
     },
     Term {
-        nfa: [1, 0, 0, 0, -1, 0, 0, 0],
-        s: 0.63e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 0, 0, -1, 0, 0, 0],
+        sin: 0.63e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 0, 0, 1, 0, 0, 0],
-        s: 0.63e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 0, 0, 1, 0, 0, 0],
+        sin: 0.63e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 1, 2, -2, 3, 0, 0, 0],
-        s: -0.46e-6,
-        c: 0.00e-6,
+        multipliers: [0, 1, 2, -2, 3, 0, 0, 0],
+        sin: -0.46e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 1, 2, -2, 1, 0, 0, 0],
-        s: -0.45e-6,
-        c: 0.00e-6,
+        multipliers: [0, 1, 2, -2, 1, 0, 0, 0],
+        sin: -0.45e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 4, -4, 4, 0, 0, 0],
-        s: -0.36e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 4, -4, 4, 0, 0, 0],
+        sin: -0.36e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 1, -1, 1, -8, 12, 0],
-        s: 0.24e-6,
-        c: 0.12e-6,
+        multipliers: [0, 0, 1, -1, 1, -8, 12, 0],
+        sin: 0.24e-6,
+        cos: 0.12e-6,
     },
     Term {
-        nfa: [0, 0, 2, 0, 0, 0, 0, 0],
-        s: -0.32e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, 0, 0, 0, 0, 0],
+        sin: -0.32e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 2, 0, 2, 0, 0, 0],
-        s: -0.28e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, 0, 2, 0, 0, 0],
+        sin: -0.28e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 2, 0, 3, 0, 0, 0],
-        s: -0.27e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 2, 0, 3, 0, 0, 0],
+        sin: -0.27e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 2, 0, 1, 0, 0, 0],
-        s: -0.26e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 2, 0, 1, 0, 0, 0],
+        sin: -0.26e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 2, -2, 0, 0, 0, 0],
-        s: 0.21e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, -2, 0, 0, 0, 0],
+        sin: 0.21e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 1, -2, 2, -3, 0, 0, 0],
-        s: -0.19e-6,
-        c: 0.00e-6,
+        multipliers: [0, 1, -2, 2, -3, 0, 0, 0],
+        sin: -0.19e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 1, -2, 2, -1, 0, 0, 0],
-        s: -0.18e-6,
-        c: 0.00e-6,
+        multipliers: [0, 1, -2, 2, -1, 0, 0, 0],
+        sin: -0.18e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 0, 0, 0, 8, -13, -1],
-        s: 0.10e-6,
-        c: -0.05e-6,
+        multipliers: [0, 0, 0, 0, 0, 8, -13, -1],
+        sin: 0.10e-6,
+        cos: -0.05e-6,
     },
     Term {
-        nfa: [0, 0, 0, 2, 0, 0, 0, 0],
-        s: -0.15e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 0, 2, 0, 0, 0, 0],
+        sin: -0.15e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [2, 0, -2, 0, -1, 0, 0, 0],
-        s: 0.14e-6,
-        c: 0.00e-6,
+        multipliers: [2, 0, -2, 0, -1, 0, 0, 0],
+        sin: 0.14e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 1, 2, -2, 2, 0, 0, 0],
-        s: 0.14e-6,
-        c: 0.00e-6,
+        multipliers: [0, 1, 2, -2, 2, 0, 0, 0],
+        sin: 0.14e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 0, -2, 1, 0, 0, 0],
-        s: -0.14e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 0, -2, 1, 0, 0, 0],
+        sin: -0.14e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 0, -2, -1, 0, 0, 0],
-        s: -0.14e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 0, -2, -1, 0, 0, 0],
+        sin: -0.14e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 4, -2, 4, 0, 0, 0],
-        s: -0.13e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 4, -2, 4, 0, 0, 0],
+        sin: -0.13e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 2, -2, 4, 0, 0, 0],
-        s: 0.11e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, -2, 4, 0, 0, 0],
+        sin: 0.11e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, -2, 0, -3, 0, 0, 0],
-        s: -0.11e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, -2, 0, -3, 0, 0, 0],
+        sin: -0.11e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, -2, 0, -1, 0, 0, 0],
-        s: -0.11e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, -2, 0, -1, 0, 0, 0],
+        sin: -0.11e-6,
+        cos: 0.00e-6,
     },
 ];
 
 /* Terms of order t^1 */
-const S1: [Term; 3] = [
+const S1: [Term<[i32; 8]>; 3] = [
     Term {
-        nfa: [0, 0, 0, 0, 2, 0, 0, 0],
-        s: -0.07e-6,
-        c: 3.57e-6,
+        multipliers: [0, 0, 0, 0, 2, 0, 0, 0],
+        sin: -0.07e-6,
+        cos: 3.57e-6,
     },
     Term {
-        nfa: [0, 0, 0, 0, 1, 0, 0, 0],
-        s: 1.73e-6,
-        c: -0.03e-6,
+        multipliers: [0, 0, 0, 0, 1, 0, 0, 0],
+        sin: 1.73e-6,
+        cos: -0.03e-6,
     },
     Term {
-        nfa: [0, 0, 2, -2, 3, 0, 0, 0],
-        s: 0.00e-6,
-        c: 0.48e-6,
+        multipliers: [0, 0, 2, -2, 3, 0, 0, 0],
+        sin: 0.00e-6,
+        cos: 0.48e-6,
     },
 ];
 
 /* Terms of order t^2 */
-const S2: [Term; 25] = [
+const S2: [Term<[i32; 8]>; 25] = [
     Term {
-        nfa: [0, 0, 0, 0, 1, 0, 0, 0],
-        s: 743.52e-6,
-        c: -0.17e-6,
+        multipliers: [0, 0, 0, 0, 1, 0, 0, 0],
+        sin: 743.52e-6,
+        cos: -0.17e-6,
     },
     Term {
-        nfa: [0, 0, 2, -2, 2, 0, 0, 0],
-        s: 56.91e-6,
-        c: 0.06e-6,
+        multipliers: [0, 0, 2, -2, 2, 0, 0, 0],
+        sin: 56.91e-6,
+        cos: 0.06e-6,
     },
     Term {
-        nfa: [0, 0, 2, 0, 2, 0, 0, 0],
-        s: 9.84e-6,
-        c: -0.01e-6,
+        multipliers: [0, 0, 2, 0, 2, 0, 0, 0],
+        sin: 9.84e-6,
+        cos: -0.01e-6,
     },
     Term {
-        nfa: [0, 0, 0, 0, 2, 0, 0, 0],
-        s: -8.85e-6,
-        c: 0.01e-6,
+        multipliers: [0, 0, 0, 0, 2, 0, 0, 0],
+        sin: -8.85e-6,
+        cos: 0.01e-6,
     },
     Term {
-        nfa: [0, 1, 0, 0, 0, 0, 0, 0],
-        s: -6.38e-6,
-        c: -0.05e-6,
+        multipliers: [0, 1, 0, 0, 0, 0, 0, 0],
+        sin: -6.38e-6,
+        cos: -0.05e-6,
     },
     Term {
-        nfa: [1, 0, 0, 0, 0, 0, 0, 0],
-        s: -3.07e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 0, 0, 0, 0, 0, 0],
+        sin: -3.07e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 1, 2, -2, 2, 0, 0, 0],
-        s: 2.23e-6,
-        c: 0.00e-6,
+        multipliers: [0, 1, 2, -2, 2, 0, 0, 0],
+        sin: 2.23e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 2, 0, 1, 0, 0, 0],
-        s: 1.67e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, 0, 1, 0, 0, 0],
+        sin: 1.67e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 2, 0, 2, 0, 0, 0],
-        s: 1.30e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 2, 0, 2, 0, 0, 0],
+        sin: 1.30e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 1, -2, 2, -2, 0, 0, 0],
-        s: 0.93e-6,
-        c: 0.00e-6,
+        multipliers: [0, 1, -2, 2, -2, 0, 0, 0],
+        sin: 0.93e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 0, -2, 0, 0, 0, 0],
-        s: 0.68e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 0, -2, 0, 0, 0, 0],
+        sin: 0.68e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 2, -2, 1, 0, 0, 0],
-        s: -0.55e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, -2, 1, 0, 0, 0],
+        sin: -0.55e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, -2, 0, -2, 0, 0, 0],
-        s: 0.53e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, -2, 0, -2, 0, 0, 0],
+        sin: 0.53e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 0, 2, 0, 0, 0, 0],
-        s: -0.27e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 0, 2, 0, 0, 0, 0],
+        sin: -0.27e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 0, 0, 1, 0, 0, 0],
-        s: -0.27e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 0, 0, 1, 0, 0, 0],
+        sin: -0.27e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, -2, -2, -2, 0, 0, 0],
-        s: -0.26e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, -2, -2, -2, 0, 0, 0],
+        sin: -0.26e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 0, 0, -1, 0, 0, 0],
-        s: -0.25e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 0, 0, -1, 0, 0, 0],
+        sin: -0.25e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 2, 0, 1, 0, 0, 0],
-        s: 0.22e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 2, 0, 1, 0, 0, 0],
+        sin: 0.22e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [2, 0, 0, -2, 0, 0, 0, 0],
-        s: -0.21e-6,
-        c: 0.00e-6,
+        multipliers: [2, 0, 0, -2, 0, 0, 0, 0],
+        sin: -0.21e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [2, 0, -2, 0, -1, 0, 0, 0],
-        s: 0.20e-6,
-        c: 0.00e-6,
+        multipliers: [2, 0, -2, 0, -1, 0, 0, 0],
+        sin: 0.20e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 2, 2, 2, 0, 0, 0],
-        s: 0.17e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, 2, 2, 0, 0, 0],
+        sin: 0.17e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [2, 0, 2, 0, 2, 0, 0, 0],
-        s: 0.13e-6,
-        c: 0.00e-6,
+        multipliers: [2, 0, 2, 0, 2, 0, 0, 0],
+        sin: 0.13e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [2, 0, 0, 0, 0, 0, 0, 0],
-        s: -0.13e-6,
-        c: 0.00e-6,
+        multipliers: [2, 0, 0, 0, 0, 0, 0, 0],
+        sin: -0.13e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [1, 0, 2, -2, 2, 0, 0, 0],
-        s: -0.12e-6,
-        c: 0.00e-6,
+        multipliers: [1, 0, 2, -2, 2, 0, 0, 0],
+        sin: -0.12e-6,
+        cos: 0.00e-6,
     },
     Term {
-        nfa: [0, 0, 2, 0, 0, 0, 0, 0],
-        s: -0.11e-6,
-        c: 0.00e-6,
+        multipliers: [0, 0, 2, 0, 0, 0, 0, 0],
+        sin: -0.11e-6,
+        cos: 0.00e-6,
     },
 ];
 
 /* Terms of order t^3 */
-const S3: [Term; 4] = [
+const S3: [Term<[i32; 8]>; 4] = [
     Term {
-        nfa: [0, 0, 0, 0, 1, 0, 0, 0],
-        s: 0.30e-6,
-        c: -23.42e-6,
+        multipliers: [0, 0, 0, 0, 1, 0, 0, 0],
+        sin: 0.30e-6,
+        cos: -23.42e-6,
     },
     Term {
-        nfa: [0, 0, 2, -2, 2, 0, 0, 0],
-        s: -0.03e-6,
-        c: -1.46e-6,
+        multipliers: [0, 0, 2, -2, 2, 0, 0, 0],
+        sin: -0.03e-6,
+        cos: -1.46e-6,
     },
     Term {
-        nfa: [0, 0, 2, 0, 2, 0, 0, 0],
-        s: -0.01e-6,
-        c: -0.25e-6,
+        multipliers: [0, 0, 2, 0, 2, 0, 0, 0],
+        sin: -0.01e-6,
+        cos: -0.25e-6,
     },
     Term {
-        nfa: [0, 0, 0, 0, 2, 0, 0, 0],
-        s: 0.00e-6,
-        c: 0.23e-6,
+        multipliers: [0, 0, 0, 0, 2, 0, 0, 0],
+        sin: 0.00e-6,
+        cos: 0.23e-6,
     },
 ];
 
 /* Terms of order t^4 */
-const S4: [Term; 1] = [Term {
-    nfa: [0, 0, 0, 0, 1, 0, 0, 0],
-    s: -0.26e-6,
-    c: -0.01e-6,
+const S4: [Term<[i32; 8]>; 1] = [Term {
+    multipliers: [0, 0, 0, 0, 1, 0, 0, 0],
+    sin: -0.26e-6,
+    cos: -0.01e-6,
 }];
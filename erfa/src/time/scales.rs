@@ -0,0 +1,275 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Time scale transformations.
+
+use crate::constants::*;
+
+/// Apply an offset (in days) to the part of a 2-part Julian Date with the
+/// smaller magnitude, as ERFA does to preserve precision.
+fn offset(date1: f64, date2: f64, days: f64) -> (f64, f64) {
+    if date1.abs() > date2.abs() {
+        (date1, date2 + days)
+    } else {
+        (date1 + days, date2)
+    }
+}
+
+/// Time scale transformation: International Atomic Time, TAI, to Terrestrial
+/// Time, TT. (`eraTaitt`)
+///
+/// Given:
+/// * `tai1`,`tai2`: TAI as a 2-part Julian Date (Note 1)
+///
+/// Returned:
+/// * `tt1`,`tt2`: TT as a 2-part Julian Date
+///
+/// # Note:
+///
+/// 1) `tai1+tai2` is Julian Date, apportioned in any convenient way between
+///    the two arguments, for example where `tai1` is the Julian Day Number and
+///    `tai2` is the fraction of a day.  The returned `tt1`,`tt2` follow suit.
+///
+/// # References:
+///
+/// * McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003), IERS Technical
+///   Note No. 32, BKG (2004)
+///
+/// * Explanatory Supplement to the Astronomical Almanac, P. Kenneth Seidelmann
+///   (ed), University Science Books (1992)
+///
+pub fn tai_to_tt(tai1: f64, tai2: f64) -> (f64, f64) {
+    /* TT minus TAI (days). */
+    const DTAT: f64 = ERFA_TTMTAI / ERFA_DAYSEC;
+
+    /* Result, safeguarding precision. */
+    offset(tai1, tai2, DTAT)
+}
+
+/// Time scale transformation: Terrestrial Time, TT, to International Atomic
+/// Time, TAI. (`eraTttai`)
+///
+/// Given:
+/// * `tt1`,`tt2`: TT as a 2-part Julian Date (Note 1)
+///
+/// Returned:
+/// * `tai1`,`tai2`: TAI as a 2-part Julian Date
+///
+/// # Note:
+///
+/// 1) `tt1+tt2` is Julian Date, apportioned in any convenient way between
+///    the two arguments, for example where `tt1` is the Julian Day Number and
+///    `tt2` is the fraction of a day.  The returned `tai1`,`tai2` follow suit.
+///
+/// # References:
+///
+/// * McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003), IERS Technical
+///   Note No. 32, BKG (2004)
+///
+/// * Explanatory Supplement to the Astronomical Almanac, P. Kenneth Seidelmann
+///   (ed), University Science Books (1992)
+///
+pub fn tt_to_tai(tt1: f64, tt2: f64) -> (f64, f64) {
+    /* TT minus TAI (days). */
+    const DTAT: f64 = ERFA_TTMTAI / ERFA_DAYSEC;
+
+    /* Result, safeguarding precision. */
+    offset(tt1, tt2, -DTAT)
+}
+
+/// Time scale transformation: Terrestrial Time, TT, to Barycentric Dynamical
+/// Time, TDB. (`eraTttdb`)
+///
+/// Given:
+/// * `tt1`,`tt2`: TT as a 2-part Julian Date (Note 1)
+/// * `dtr`: TDB-TT in seconds (Note 2)
+///
+/// Returned:
+/// * `tdb1`,`tdb2`: TDB as a 2-part Julian Date
+///
+/// # Notes:
+///
+/// 1) `tt1+tt2` is Julian Date, apportioned in any convenient way between
+///    the two arguments, for example where `tt1` is the Julian Day Number and
+///    `tt2` is the fraction of a day.  The returned `tdb1`,`tdb2` follow suit.
+///
+/// 2) The argument `dtr` represents the quasi-periodic component of the GR
+///    transformation between TT and TCB.  It is dependent upon the adopted
+///    solar-system ephemeris, and can be obtained by numerical integration, by
+///    interrogating a precomputed time ephemeris or by evaluating a model such
+///    as that implemented in the ERFA function `eraDtdb`.  The quantity is
+///    dominated by an annual term of 1.7 ms amplitude.
+///
+/// 3) TDB is essentially the same as Teph, the time argument for the JPL solar
+///    system ephemerides.
+///
+/// # References:
+///
+/// * McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003), IERS Technical
+///   Note No. 32, BKG (2004)
+///
+/// * IAU 2006 Resolution B3
+///
+pub fn tt_to_tdb(tt1: f64, tt2: f64, dtr: f64) -> (f64, f64) {
+    /* Result, safeguarding precision. */
+    let dtrd = dtr / ERFA_DAYSEC;
+    offset(tt1, tt2, dtrd)
+}
+
+/// Time scale transformation: Barycentric Dynamical Time, TDB, to Terrestrial
+/// Time, TT. (`eraTdbtt`)
+///
+/// Given:
+/// * `tdb1`,`tdb2`: TDB as a 2-part Julian Date (Note 1)
+/// * `dtr`: TDB-TT in seconds (Note 2)
+///
+/// Returned:
+/// * `tt1`,`tt2`: TT as a 2-part Julian Date
+///
+/// # Notes:
+///
+/// 1) `tdb1+tdb2` is Julian Date, apportioned in any convenient way between
+///    the two arguments, for example where `tdb1` is the Julian Day Number and
+///    `tdb2` is the fraction of a day.  The returned `tt1`,`tt2` follow suit.
+///
+/// 2) The argument `dtr` represents the quasi-periodic component of the GR
+///    transformation between TT and TCB.  It is dependent upon the adopted
+///    solar-system ephemeris, and can be obtained by numerical integration, by
+///    interrogating a precomputed time ephemeris or by evaluating a model such
+///    as that implemented in the ERFA function `eraDtdb`.  The quantity is
+///    dominated by an annual term of 1.7 ms amplitude.
+///
+/// 3) TDB is essentially the same as Teph, the time argument for the JPL solar
+///    system ephemerides.
+///
+/// # References:
+///
+/// * McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003), IERS Technical
+///   Note No. 32, BKG (2004)
+///
+/// * IAU 2006 Resolution B3
+///
+pub fn tdb_to_tt(tdb1: f64, tdb2: f64, dtr: f64) -> (f64, f64) {
+    /* Result, safeguarding precision. */
+    let dtrd = dtr / ERFA_DAYSEC;
+    offset(tdb1, tdb2, -dtrd)
+}
+
+/// Time scale transformation: Terrestrial Time, TT, to Universal Time, UT1.
+/// (`eraTtut1`)
+///
+/// Given:
+/// * `tt1`,`tt2`: TT as a 2-part Julian Date (Note 1)
+/// * `dt`: TT-UT1 in seconds (Note 2)
+///
+/// Returned:
+/// * `ut11`,`ut12`: UT1 as a 2-part Julian Date
+///
+/// # Notes:
+///
+/// 1) `tt1+tt2` is Julian Date, apportioned in any convenient way between
+///    the two arguments, for example where `tt1` is the Julian Day Number and
+///    `tt2` is the fraction of a day.  The returned `ut11`,`ut12` follow suit.
+///
+/// 2) The argument `dt` is classical Delta T.
+///
+/// # Reference:
+///
+/// * Explanatory Supplement to the Astronomical Almanac, P. Kenneth Seidelmann
+///   (ed), University Science Books (1992)
+///
+pub fn tt_to_ut1(tt1: f64, tt2: f64, dt: f64) -> (f64, f64) {
+    /* Result, safeguarding precision. */
+    let dtd = dt / ERFA_DAYSEC;
+    offset(tt1, tt2, -dtd)
+}
+
+/// Time scale transformation: Universal Time, UT1, to Terrestrial Time, TT.
+/// (`eraUt1tt`)
+///
+/// Given:
+/// * `ut11`,`ut12`: UT1 as a 2-part Julian Date (Note 1)
+/// * `dt`: TT-UT1 in seconds (Note 2)
+///
+/// Returned:
+/// * `tt1`,`tt2`: TT as a 2-part Julian Date
+///
+/// # Notes:
+///
+/// 1) `ut11+ut12` is Julian Date, apportioned in any convenient way between
+///    the two arguments, for example where `ut11` is the Julian Day Number and
+///    `ut12` is the fraction of a day.  The returned `tt1`,`tt2` follow suit.
+///
+/// 2) The argument `dt` is classical Delta T.
+///
+/// # Reference:
+///
+/// * Explanatory Supplement to the Astronomical Almanac, P. Kenneth Seidelmann
+///   (ed), University Science Books (1992)
+///
+pub fn ut1_to_tt(ut11: f64, ut12: f64, dt: f64) -> (f64, f64) {
+    /* Result, safeguarding precision. */
+    let dtd = dt / ERFA_DAYSEC;
+    offset(ut11, ut12, dtd)
+}
+
+/// Time scale transformation: International Atomic Time, TAI, to Universal
+/// Time, UT1. (`eraTaiut1`)
+///
+/// Given:
+/// * `tai1`,`tai2`: TAI as a 2-part Julian Date (Note 1)
+/// * `dta`: UT1-TAI in seconds (Note 2)
+///
+/// Returned:
+/// * `ut11`,`ut12`: UT1 as a 2-part Julian Date
+///
+/// # Notes:
+///
+/// 1) `tai1+tai2` is Julian Date, apportioned in any convenient way between
+///    the two arguments, for example where `tai1` is the Julian Day Number and
+///    `tai2` is the fraction of a day.  The returned `ut11`,`ut12` follow suit.
+///
+/// 2) The argument `dta`, i.e. UT1-TAI, is an observed quantity, and is
+///    available from IERS tables.
+///
+/// # Reference:
+///
+/// * Explanatory Supplement to the Astronomical Almanac, P. Kenneth Seidelmann
+///   (ed), University Science Books (1992)
+///
+pub fn tai_to_ut1(tai1: f64, tai2: f64, dta: f64) -> (f64, f64) {
+    /* Result, safeguarding precision. */
+    let dtad = dta / ERFA_DAYSEC;
+    offset(tai1, tai2, dtad)
+}
+
+/// Time scale transformation: Universal Time, UT1, to International Atomic
+/// Time, TAI. (`eraUt1tai`)
+///
+/// Given:
+/// * `ut11`,`ut12`: UT1 as a 2-part Julian Date (Note 1)
+/// * `dta`: UT1-TAI in seconds (Note 2)
+///
+/// Returned:
+/// * `tai1`,`tai2`: TAI as a 2-part Julian Date
+///
+/// # Notes:
+///
+/// 1) `ut11+ut12` is Julian Date, apportioned in any convenient way between
+///    the two arguments, for example where `ut11` is the Julian Day Number and
+///    `ut12` is the fraction of a day.  The returned `tai1`,`tai2` follow suit.
+///
+/// 2) The argument `dta`, i.e. UT1-TAI, is an observed quantity, and is
+///    available from IERS tables.
+///
+/// # Reference:
+///
+/// * Explanatory Supplement to the Astronomical Almanac, P. Kenneth Seidelmann
+///   (ed), University Science Books (1992)
+///
+pub fn ut1_to_tai(ut11: f64, ut12: f64, dta: f64) -> (f64, f64) {
+    /* Result, safeguarding precision. */
+    let dtad = dta / ERFA_DAYSEC;
+    offset(ut11, ut12, -dtad)
+}
@@ -0,0 +1,74 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Variants of the [`time`](super) functions that take [`Epoch`]s tagged with
+//! the time scale they require, so that e.g. UT1 and TT can't be swapped. See
+//! the untyped functions for full documentation; the results are identical.
+
+use super::{Epoch, TT, UT1};
+use crate::angle::Angle;
+
+/// Greenwich apparent sidereal time (consistent with IAU 2000 and 2006
+/// resolutions). (`eraGst06a`)
+///
+/// Given:
+/// * `ut`: UT1 epoch
+/// * `tt`: TT epoch
+///
+/// Returned:
+/// * Greenwich apparent sidereal time, in the range 0 to 2pi
+///
+/// See [`gst06a`](super::gst06a).
+///
+pub fn gst06a(ut: Epoch<UT1>, tt: Epoch<TT>) -> Angle {
+    let (uta, utb) = ut.parts();
+    let (tta, ttb) = tt.parts();
+    Angle::from_radians(super::gst06a(uta, utb, tta, ttb))
+}
+
+/// Greenwich mean sidereal time (consistent with IAU 2006 precession).
+/// (`eraGmst06`)
+///
+/// Given:
+/// * `ut`: UT1 epoch
+/// * `tt`: TT epoch
+///
+/// Returned:
+/// * Greenwich mean sidereal time, in the range 0 to 2pi
+///
+/// See [`gmst06`](super::gmst06).
+///
+pub fn gmst06(ut: Epoch<UT1>, tt: Epoch<TT>) -> Angle {
+    let (uta, utb) = ut.parts();
+    let (tta, ttb) = tt.parts();
+    Angle::from_radians(super::gmst06(uta, utb, tta, ttb))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_matches_untyped() {
+        for (ut1, ut2, tt1, tt2) in [
+            (2453736.5, 0.0, 2453736.5, 0.0),
+            (2450123.5, 0.2, 2450123.5, 0.2007),
+            (2400000.5, 53736.0, 2400000.5, 53736.0),
+        ] {
+            let ut = Epoch::<UT1>::new(ut1, ut2);
+            let tt = Epoch::<TT>::new(tt1, tt2);
+            // The epochs re-apportion the dates, so allow for rounding.
+            approx::assert_abs_diff_eq!(
+                gst06a(ut, tt).radians(),
+                super::super::gst06a(ut1, ut2, tt1, tt2),
+                epsilon = 1e-12
+            );
+            approx::assert_abs_diff_eq!(
+                gmst06(ut, tt).radians(),
+                super::super::gmst06(ut1, ut2, tt1, tt2),
+                epsilon = 1e-12
+            );
+        }
+    }
+}
@@ -0,0 +1,396 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Variants of the [`transform`](super) functions that work on slices of
+//! inputs, writing into slices of outputs. Terms that are common to every
+//! element (e.g. functions of the site latitude, or of the reference
+//! ellipsoid) are computed once per call rather than once per element. The
+//! results are identical to calling the scalar functions element by element.
+//!
+//! Functions of the site latitude can also be kept across calls with
+//! [`SiteLatitude`]; the `*_sites` variants take one per element, which suits
+//! many antennas at different latitudes.
+//!
+//! # Panics
+//!
+//! All of these functions panic if the input and output slices don't have the
+//! same length.
+
+use super::{
+    azel_to_hadec_sc, check_ellipsoid, geocentric_to_geodetic_unchecked, geodetic_to_geocentric_sc,
+    hadec_to_azel_sc, hadec_to_parallactic_angle_sc,
+};
+use crate::{ellipsoid::ReferenceEllipsoid, math, ErfaError};
+
+/// A site latitude with its sine and cosine precomputed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SiteLatitude {
+    phi: f64,
+    sin: f64,
+    cos: f64,
+}
+
+impl SiteLatitude {
+    /// Precompute the functions of the latitude `phi` (radians).
+    pub fn new(phi: f64) -> Self {
+        let (sin, cos) = math::sin_cos(phi);
+        Self { phi, sin, cos }
+    }
+
+    /// The latitude (radians).
+    pub fn radians(self) -> f64 {
+        self.phi
+    }
+
+    /// See [`azel_to_hadec`](super::azel_to_hadec).
+    pub fn azel_to_hadec(self, az: f64, el: f64) -> (f64, f64) {
+        azel_to_hadec_sc(az, el, self.sin, self.cos)
+    }
+
+    /// See [`hadec_to_azel`](super::hadec_to_azel).
+    pub fn hadec_to_azel(self, ha: f64, dec: f64) -> (f64, f64) {
+        hadec_to_azel_sc(ha, dec, self.sin, self.cos)
+    }
+
+    /// See [`hadec_to_parallactic_angle`](super::hadec_to_parallactic_angle).
+    pub fn hadec_to_parallactic_angle(self, ha: f64, dec: f64) -> f64 {
+        hadec_to_parallactic_angle_sc(ha, dec, self.sin, self.cos)
+    }
+}
+
+impl From<f64> for SiteLatitude {
+    fn from(phi: f64) -> Self {
+        Self::new(phi)
+    }
+}
+
+pub(crate) fn check_lengths(function: &str, n: usize, lengths: &[usize]) {
+    assert!(
+        lengths.iter().all(|&l| l == n),
+        "{function}: slice lengths differ ({n} and {lengths:?})"
+    );
+}
+
+/// Horizon to equatorial coordinates for many directions seen from one site.
+/// See [`azel_to_hadec`](super::azel_to_hadec).
+pub fn azel_to_hadec<P: Into<SiteLatitude>>(
+    az: &[f64],
+    el: &[f64],
+    phi: P,
+    ha: &mut [f64],
+    dec: &mut [f64],
+) {
+    check_lengths("azel_to_hadec", az.len(), &[el.len(), ha.len(), dec.len()]);
+    let phi = phi.into();
+    for (i, (&az, &el)) in az.iter().zip(el).enumerate() {
+        (ha[i], dec[i]) = phi.azel_to_hadec(az, el);
+    }
+}
+
+/// Horizon to equatorial coordinates, one site per element. See
+/// [`azel_to_hadec`](super::azel_to_hadec).
+pub fn azel_to_hadec_sites(
+    az: &[f64],
+    el: &[f64],
+    sites: &[SiteLatitude],
+    ha: &mut [f64],
+    dec: &mut [f64],
+) {
+    check_lengths(
+        "azel_to_hadec_sites",
+        az.len(),
+        &[el.len(), sites.len(), ha.len(), dec.len()],
+    );
+    for (i, ((&az, &el), site)) in az.iter().zip(el).zip(sites).enumerate() {
+        (ha[i], dec[i]) = site.azel_to_hadec(az, el);
+    }
+}
+
+/// Equatorial to horizon coordinates for many directions seen from one site.
+/// See [`hadec_to_azel`](super::hadec_to_azel).
+pub fn hadec_to_azel<P: Into<SiteLatitude>>(
+    ha: &[f64],
+    dec: &[f64],
+    phi: P,
+    az: &mut [f64],
+    el: &mut [f64],
+) {
+    check_lengths("hadec_to_azel", ha.len(), &[dec.len(), az.len(), el.len()]);
+    let phi = phi.into();
+    for (i, (&ha, &dec)) in ha.iter().zip(dec).enumerate() {
+        (az[i], el[i]) = phi.hadec_to_azel(ha, dec);
+    }
+}
+
+/// Equatorial to horizon coordinates, one site per element. See
+/// [`hadec_to_azel`](super::hadec_to_azel).
+pub fn hadec_to_azel_sites(
+    ha: &[f64],
+    dec: &[f64],
+    sites: &[SiteLatitude],
+    az: &mut [f64],
+    el: &mut [f64],
+) {
+    check_lengths(
+        "hadec_to_azel_sites",
+        ha.len(),
+        &[dec.len(), sites.len(), az.len(), el.len()],
+    );
+    for (i, ((&ha, &dec), site)) in ha.iter().zip(dec).zip(sites).enumerate() {
+        (az[i], el[i]) = site.hadec_to_azel(ha, dec);
+    }
+}
+
+/// Parallactic angles for many directions seen from one site. See
+/// [`hadec_to_parallactic_angle`](super::hadec_to_parallactic_angle).
+pub fn hadec_to_parallactic_angle<P: Into<SiteLatitude>>(
+    ha: &[f64],
+    dec: &[f64],
+    phi: P,
+    pa: &mut [f64],
+) {
+    check_lengths(
+        "hadec_to_parallactic_angle",
+        ha.len(),
+        &[dec.len(), pa.len()],
+    );
+    let phi = phi.into();
+    for ((&ha, &dec), pa) in ha.iter().zip(dec).zip(pa) {
+        *pa = phi.hadec_to_parallactic_angle(ha, dec);
+    }
+}
+
+/// Parallactic angles, one site per element. See
+/// [`hadec_to_parallactic_angle`](super::hadec_to_parallactic_angle).
+pub fn hadec_to_parallactic_angle_sites(
+    ha: &[f64],
+    dec: &[f64],
+    sites: &[SiteLatitude],
+    pa: &mut [f64],
+) {
+    check_lengths(
+        "hadec_to_parallactic_angle_sites",
+        ha.len(),
+        &[dec.len(), sites.len(), pa.len()],
+    );
+    for (((&ha, &dec), site), pa) in ha.iter().zip(dec).zip(sites).zip(pa) {
+        *pa = site.hadec_to_parallactic_angle(ha, dec);
+    }
+}
+
+/// Transform many geocentric vectors to geodetic coordinates (`[elong, phi,
+/// height]`). See [`geocentric_to_geodetic`](super::geocentric_to_geodetic).
+///
+/// # Errors
+///
+/// As for [`geocentric_to_geodetic_inner`](super::geocentric_to_geodetic_inner);
+/// the ellipsoid is checked once, before any outputs are written.
+pub fn geocentric_to_geodetic<E: Into<ReferenceEllipsoid>>(
+    e: E,
+    xyz: &[[f64; 3]],
+    geodetic: &mut [[f64; 3]],
+) -> Result<(), ErfaError> {
+    check_lengths("geocentric_to_geodetic", xyz.len(), &[geodetic.len()]);
+    let (a, f) = e.into().get_params();
+    check_ellipsoid("geocentric_to_geodetic_inner", a, f)?;
+    for (&xyz, out) in xyz.iter().zip(geodetic) {
+        *out = geocentric_to_geodetic_unchecked(a, f, xyz);
+    }
+    Ok(())
+}
+
+/// Transform many geodetic coordinates (e.g. antenna positions) to geocentric
+/// vectors. See [`geodetic_to_geocentric`](super::geodetic_to_geocentric).
+///
+/// # Errors
+///
+/// This function will return an error at the first unrealistic element;
+/// outputs before it will have been written.
+pub fn geodetic_to_geocentric<E: Into<ReferenceEllipsoid>>(
+    e: E,
+    elong: &[f64],
+    phi: &[f64],
+    height: &[f64],
+    xyz: &mut [[f64; 3]],
+) -> Result<(), ErfaError> {
+    check_lengths(
+        "geodetic_to_geocentric",
+        elong.len(),
+        &[phi.len(), height.len(), xyz.len()],
+    );
+    let (a, f) = e.into().get_params();
+    let w = 1.0 - f;
+    let w = w * w;
+    for (((&elong, &phi), &height), out) in elong.iter().zip(phi).zip(height).zip(xyz) {
+        let (sp, cp) = math::sin_cos(phi);
+        *out = geodetic_to_geocentric_sc(a, w, elong, sp, cp, height).ok_or(
+            ErfaError::Unrealistic {
+                function: "geodetic_to_geocentric_inner",
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// P-vectors to spherical coordinates. See
+/// [`cartesian_to_spherical`](super::cartesian_to_spherical).
+pub fn cartesian_to_spherical(p: &[[f64; 3]], theta: &mut [f64], phi: &mut [f64]) {
+    check_lengths("cartesian_to_spherical", p.len(), &[theta.len(), phi.len()]);
+    for (i, &p) in p.iter().enumerate() {
+        (theta[i], phi[i]) = super::cartesian_to_spherical(p);
+    }
+}
+
+/// Spherical coordinates to unit vectors. See
+/// [`spherical_to_cartesian`](super::spherical_to_cartesian).
+pub fn spherical_to_cartesian(theta: &[f64], phi: &[f64], c: &mut [[f64; 3]]) {
+    check_lengths("spherical_to_cartesian", theta.len(), &[phi.len(), c.len()]);
+    for ((&theta, &phi), c) in theta.iter().zip(phi).zip(c) {
+        *c = super::spherical_to_cartesian(theta, phi);
+    }
+}
+
+/// P-vectors to spherical polar coordinates. See
+/// [`cartesian_to_spherical_polar`](super::cartesian_to_spherical_polar).
+pub fn cartesian_to_spherical_polar(
+    p: &[[f64; 3]],
+    theta: &mut [f64],
+    phi: &mut [f64],
+    r: &mut [f64],
+) {
+    check_lengths(
+        "cartesian_to_spherical_polar",
+        p.len(),
+        &[theta.len(), phi.len(), r.len()],
+    );
+    for (i, &p) in p.iter().enumerate() {
+        (theta[i], phi[i], r[i]) = super::cartesian_to_spherical_polar(p);
+    }
+}
+
+/// Spherical polar coordinates to p-vectors. See
+/// [`spherical_polar_to_cartesian`](super::spherical_polar_to_cartesian).
+pub fn spherical_polar_to_cartesian(theta: &[f64], phi: &[f64], r: &[f64], p: &mut [[f64; 3]]) {
+    check_lengths(
+        "spherical_polar_to_cartesian",
+        theta.len(),
+        &[phi.len(), r.len(), p.len()],
+    );
+    for (((&theta, &phi), &r), p) in theta.iter().zip(phi).zip(r).zip(p) {
+        *p = super::spherical_polar_to_cartesian(theta, phi, r);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+    use crate::{transform as scalar, Ellipsoid};
+
+    const PHI: f64 = -0.4660608339;
+
+    fn directions() -> (Vec<f64>, Vec<f64>) {
+        let n = 257;
+        let a = (0..n).map(|i| -3.1 + 6.2 * i as f64 / n as f64).collect();
+        let b = (0..n)
+            .map(|i| -1.5 + 3.0 * (i * 7 % n) as f64 / n as f64)
+            .collect();
+        (a, b)
+    }
+
+    #[test]
+    fn test_horizon_batches_match_scalar() {
+        let (ha, dec) = directions();
+        let n = ha.len();
+        let (mut az, mut el, mut pa) = (vec![0.0; n], vec![0.0; n], vec![0.0; n]);
+        hadec_to_azel(&ha, &dec, PHI, &mut az, &mut el);
+        hadec_to_parallactic_angle(&ha, &dec, PHI, &mut pa);
+        let (mut ha2, mut dec2) = (vec![0.0; n], vec![0.0; n]);
+        azel_to_hadec(&az, &el, PHI, &mut ha2, &mut dec2);
+        for i in 0..n {
+            assert_eq!((az[i], el[i]), scalar::hadec_to_azel(ha[i], dec[i], PHI));
+            assert_eq!(
+                pa[i],
+                scalar::hadec_to_parallactic_angle(ha[i], dec[i], PHI)
+            );
+            assert_eq!((ha2[i], dec2[i]), scalar::azel_to_hadec(az[i], el[i], PHI));
+        }
+
+        // One site per element.
+        let sites: Vec<_> = dec.iter().map(|&phi| SiteLatitude::new(phi)).collect();
+        hadec_to_azel_sites(&ha, &ha, &sites, &mut az, &mut el);
+        hadec_to_parallactic_angle_sites(&ha, &ha, &sites, &mut pa);
+        azel_to_hadec_sites(&az, &el, &sites, &mut ha2, &mut dec2);
+        for i in 0..n {
+            assert_eq!((az[i], el[i]), scalar::hadec_to_azel(ha[i], ha[i], dec[i]));
+            assert_eq!(
+                pa[i],
+                scalar::hadec_to_parallactic_angle(ha[i], ha[i], dec[i])
+            );
+            assert_eq!(
+                (ha2[i], dec2[i]),
+                scalar::azel_to_hadec(az[i], el[i], dec[i])
+            );
+        }
+    }
+
+    #[test]
+    fn test_geodetic_batches_match_scalar() {
+        let (elong, phi) = directions();
+        let n = elong.len();
+        let height: Vec<_> = (0..n).map(|i| 100.0 * i as f64 - 400.0).collect();
+        let mut xyz = vec![[0.0; 3]; n];
+        geodetic_to_geocentric(Ellipsoid::WGS84, &elong, &phi, &height, &mut xyz).unwrap();
+        let mut geodetic = vec![[0.0; 3]; n];
+        geocentric_to_geodetic(Ellipsoid::WGS84, &xyz, &mut geodetic).unwrap();
+        for i in 0..n {
+            assert_eq!(
+                xyz[i],
+                scalar::geodetic_to_geocentric(Ellipsoid::WGS84, elong[i], phi[i], height[i])
+                    .unwrap()
+            );
+            assert_eq!(
+                geodetic[i],
+                scalar::geocentric_to_geodetic(Ellipsoid::WGS84, xyz[i]).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_spherical_batches_match_scalar() {
+        let (theta, phi) = directions();
+        let n = theta.len();
+        let r: Vec<_> = (0..n).map(|i| i as f64 * 0.5).collect();
+        let mut c = vec![[0.0; 3]; n];
+        spherical_to_cartesian(&theta, &phi, &mut c);
+        let mut p = vec![[0.0; 3]; n];
+        spherical_polar_to_cartesian(&theta, &phi, &r, &mut p);
+        let (mut t, mut f, mut rr) = (vec![0.0; n], vec![0.0; n], vec![0.0; n]);
+        cartesian_to_spherical_polar(&p, &mut t, &mut f, &mut rr);
+        for i in 0..n {
+            assert_eq!(c[i], scalar::spherical_to_cartesian(theta[i], phi[i]));
+            assert_eq!(
+                p[i],
+                scalar::spherical_polar_to_cartesian(theta[i], phi[i], r[i])
+            );
+            assert_eq!(
+                (t[i], f[i], rr[i]),
+                scalar::cartesian_to_spherical_polar(p[i])
+            );
+        }
+        cartesian_to_spherical(&c, &mut t, &mut f);
+        for i in 0..n {
+            assert_eq!((t[i], f[i]), scalar::cartesian_to_spherical(c[i]));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_mismatch_panics() {
+        let mut az = [0.0; 2];
+        let mut el = [0.0; 3];
+        hadec_to_azel(&[0.0; 3], &[0.0; 3], PHI, &mut az, &mut el);
+    }
+}
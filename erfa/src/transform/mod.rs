@@ -4,7 +4,10 @@
 
 //! Code to transform coordinates.
 
-use crate::{ellipsoid::Ellipsoid, ErfaError};
+pub mod batch;
+pub mod typed;
+
+use crate::{ellipsoid::ReferenceEllipsoid, math, real::Real, ErfaError};
 
 /// P-vector to spherical coordinates. (`eraC2s`)
 ///
@@ -27,8 +30,12 @@ pub fn cartesian_to_spherical(p: [f64; 3]) -> (f64, f64) {
     let z = p[2];
     let d2 = x * x + y * y;
 
-    let theta = if d2 == 0.0 { 0.0 } else { y.atan2(x) };
-    let phi = if z == 0.0 { 0.0 } else { z.atan2(d2.sqrt()) };
+    let theta = if d2 == 0.0 { 0.0 } else { math::atan2(y, x) };
+    let phi = if z == 0.0 {
+        0.0
+    } else {
+        math::atan2(z, math::sqrt(d2))
+    };
 
     (theta, phi)
 }
@@ -43,11 +50,156 @@ pub fn cartesian_to_spherical(p: [f64; 3]) -> (f64, f64) {
 /// * direction cosines
 ///
 pub fn spherical_to_cartesian(theta: f64, phi: f64) -> [f64; 3] {
-    let (sp, cp) = phi.sin_cos();
-    let (st, ct) = theta.sin_cos();
+    let (sp, cp) = math::sin_cos(phi);
+    let (st, ct) = math::sin_cos(theta);
     [ct * cp, st * cp, sp]
 }
 
+/// P-vector to spherical polar coordinates. (`eraP2s`)
+///
+/// Given:
+/// * `p`: p-vector
+///
+/// Returned:
+/// * `theta`: longitude angle (radians)
+/// * `phi`: latitude angle (radians)
+/// * `r`: radial distance
+///
+/// # Notes:
+///
+/// 1) If `p` is null, zero `theta`, `phi` and `r` are returned.
+///
+/// 2) At either pole, zero `theta` is returned.
+///
+pub fn cartesian_to_spherical_polar(p: [f64; 3]) -> (f64, f64, f64) {
+    let (theta, phi) = cartesian_to_spherical(p);
+    let r = crate::vectors_and_matrices::modulus(p);
+
+    (theta, phi, r)
+}
+
+/// Convert spherical polar coordinates to p-vector. (`eraS2p`)
+///
+/// Given:
+/// * `theta`: longitude angle (radians)
+/// * `phi`: latitude angle (radians)
+/// * `r`: radial distance
+///
+/// Returned:
+/// * Cartesian coordinates
+///
+pub fn spherical_polar_to_cartesian(theta: f64, phi: f64, r: f64) -> [f64; 3] {
+    let u = spherical_to_cartesian(theta, phi);
+    crate::vectors_and_matrices::multiply(r, u)
+}
+
+/// Convert position/velocity from Cartesian to spherical coordinates.
+/// (`eraPv2s`)
+///
+/// Given:
+/// * `pv`: pv-vector
+///
+/// Returned:
+/// * `theta`: longitude angle (radians)
+/// * `phi`: latitude angle (radians)
+/// * `r`: radial distance
+/// * `td`: rate of change of `theta`
+/// * `pd`: rate of change of `phi`
+/// * `rd`: rate of change of `r`
+///
+/// # Notes:
+///
+/// 1) If the position part of `pv` is null, `theta`, `phi`, `td` and `pd` are
+///    indeterminate.  This is handled by extrapolating the position through
+///    unit time by using the velocity part of `pv`.  This moves the origin
+///    without changing the direction of the velocity component.  If the
+///    position and velocity components of `pv` are both null, zeroes are
+///    returned for all six results.
+///
+/// 2) If the position is a pole, `theta`, `td` and `pd` are indeterminate.  In
+///    such cases zeroes are returned for all three.
+///
+pub fn pv_to_spherical_polar(pv: [[f64; 3]; 2]) -> (f64, f64, f64, f64, f64, f64) {
+    /* Components of position/velocity vector. */
+    let [mut x, mut y, mut z] = pv[0];
+    let [xd, yd, zd] = pv[1];
+
+    /* Component of r in XY plane squared. */
+    let mut rxy2 = x * x + y * y;
+
+    /* Modulus squared. */
+    let mut r2 = rxy2 + z * z;
+
+    /* Modulus. */
+    let rtrue = math::sqrt(r2);
+
+    /* If null vector, move the origin along the direction of movement. */
+    let mut rw = rtrue;
+    if rtrue == 0.0 {
+        x = xd;
+        y = yd;
+        z = zd;
+        rxy2 = x * x + y * y;
+        r2 = rxy2 + z * z;
+        rw = math::sqrt(r2);
+    }
+
+    /* Position and velocity in spherical coordinates. */
+    let rxy = math::sqrt(rxy2);
+    let xyp = x * xd + y * yd;
+    let (theta, phi, td, pd) = if rxy2 != 0.0 {
+        (
+            math::atan2(y, x),
+            math::atan2(z, rxy),
+            (x * yd - y * xd) / rxy2,
+            (zd * rxy2 - z * xyp) / (r2 * rxy),
+        )
+    } else {
+        let phi = if z != 0.0 { math::atan2(z, rxy) } else { 0.0 };
+        (0.0, phi, 0.0, 0.0)
+    };
+    let r = rtrue;
+    let rd = if rw != 0.0 { (xyp + z * zd) / rw } else { 0.0 };
+
+    (theta, phi, r, td, pd, rd)
+}
+
+/// Convert position/velocity from spherical to Cartesian coordinates.
+/// (`eraS2pv`)
+///
+/// Given:
+/// * `theta`: longitude angle (radians)
+/// * `phi`: latitude angle (radians)
+/// * `r`: radial distance
+/// * `td`: rate of change of `theta`
+/// * `pd`: rate of change of `phi`
+/// * `rd`: rate of change of `r`
+///
+/// Returned:
+/// * `pv`: pv-vector
+///
+pub fn spherical_polar_to_pv(
+    theta: f64,
+    phi: f64,
+    r: f64,
+    td: f64,
+    pd: f64,
+    rd: f64,
+) -> [[f64; 3]; 2] {
+    let (st, ct) = math::sin_cos(theta);
+    let (sp, cp) = math::sin_cos(phi);
+    let rcp = r * cp;
+    let x = rcp * ct;
+    let y = rcp * st;
+    let rpd = r * pd;
+    let w = rpd * sp - cp * rd;
+
+    [
+        [x, y, r * sp],
+        [-y * td - w * ct, x * td - w * st, rpd * cp + sp * rd],
+    ]
+}
+
 /// Horizon to equatorial coordinates:  transform azimuth and altitude to hour
 /// angle and declination. (`eraAe2hd`)
 ///
@@ -96,11 +248,16 @@ pub fn spherical_to_cartesian(theta: f64, phi: f64) -> [f64; 3] {
 ///
 /// 8) Again for efficiency, no range checking of arguments is carried out.
 ///
-pub fn azel_to_hadec(az: f64, el: f64, phi: f64) -> (f64, f64) {
+pub fn azel_to_hadec<R: Real>(az: R, el: R, phi: R) -> (R, R) {
+    let (sp, cp) = phi.sin_cos();
+    azel_to_hadec_sc(az, el, sp, cp)
+}
+
+/// [`azel_to_hadec`] given the sine and cosine of the site latitude.
+pub(crate) fn azel_to_hadec_sc<R: Real>(az: R, el: R, sp: R, cp: R) -> (R, R) {
     /* Useful trig functions. */
     let (sa, ca) = az.sin_cos();
     let (se, ce) = el.sin_cos();
-    let (sp, cp) = phi.sin_cos();
 
     /* HA,Dec unit vector. */
     let x = -ca * ce * sp + se * cp;
@@ -109,7 +266,8 @@ pub fn azel_to_hadec(az: f64, el: f64, phi: f64) -> (f64, f64) {
 
     /* To spherical. */
     let r = (x * x + y * y).sqrt();
-    let ha = if r != 0.0 { y.atan2(x) } else { 0.0 };
+    let zero = R::from_f64(0.0);
+    let ha = if r != zero { y.atan2(x) } else { zero };
     let dec = z.atan2(r);
 
     (ha, dec)
@@ -161,11 +319,16 @@ pub fn azel_to_hadec(az: f64, el: f64, phi: f64) -> (f64, f64) {
 ///
 /// 7) Again for efficiency, no range checking of arguments is carried out.
 ///
-pub fn hadec_to_azel(ha: f64, dec: f64, phi: f64) -> (f64, f64) {
+pub fn hadec_to_azel<R: Real>(ha: R, dec: R, phi: R) -> (R, R) {
+    let (sp, cp) = phi.sin_cos();
+    hadec_to_azel_sc(ha, dec, sp, cp)
+}
+
+/// [`hadec_to_azel`] given the sine and cosine of the site latitude.
+pub(crate) fn hadec_to_azel_sc<R: Real>(ha: R, dec: R, sp: R, cp: R) -> (R, R) {
     /* Useful trig functions. */
     let (sh, ch) = ha.sin_cos();
     let (sd, cd) = dec.sin_cos();
-    let (sp, cp) = phi.sin_cos();
 
     /* Az,Alt unit vector. */
     let x = -ch * cd * sp + sd * cp;
@@ -174,8 +337,9 @@ pub fn hadec_to_azel(ha: f64, dec: f64, phi: f64) -> (f64, f64) {
 
     /* To spherical. */
     let r = (x * x + y * y).sqrt();
-    let a = if r != 0.0 { y.atan2(x) } else { 0.0 };
-    let az = if a < 0.0 {
+    let zero = R::from_f64(0.0);
+    let a = if r != zero { y.atan2(x) } else { zero };
+    let az = if a < zero {
         a + crate::constants::ERFA_D2PI
     } else {
         a
@@ -223,13 +387,19 @@ pub fn hadec_to_azel(ha: f64, dec: f64, phi: f64) -> (f64, f64) {
 /// (Green, 1977), p49.
 ///
 pub fn hadec_to_parallactic_angle(ha: f64, dec: f64, phi: f64) -> f64 {
-    let (sp, cp) = phi.sin_cos();
-    let (sha, cha) = ha.sin_cos();
-    let (sdec, cdec) = dec.sin_cos();
+    let (sp, cp) = math::sin_cos(phi);
+    hadec_to_parallactic_angle_sc(ha, dec, sp, cp)
+}
+
+/// [`hadec_to_parallactic_angle`] given the sine and cosine of the site
+/// latitude.
+pub(crate) fn hadec_to_parallactic_angle_sc(ha: f64, dec: f64, sp: f64, cp: f64) -> f64 {
+    let (sha, cha) = math::sin_cos(ha);
+    let (sdec, cdec) = math::sin_cos(dec);
     let sqsz = cp * sha;
     let cqsz = sp * cdec - cp * sdec * cha;
     if sqsz != 0.0 || cqsz != 0.0 {
-        sqsz.atan2(cqsz)
+        math::atan2(sqsz, cqsz)
     } else {
         0.0
     }
@@ -239,7 +409,7 @@ pub fn hadec_to_parallactic_angle(ha: f64, dec: f64, phi: f64) -> f64 {
 /// ellipsoid. (`eraGc2gd`)
 ///
 /// Given:
-/// * `e`: ellipsoid identifier
+/// * `e`: ellipsoid identifier (Note 2)
 /// * `xyz`: geocentric vector (Note 1)
 ///
 /// Returned:
@@ -247,15 +417,27 @@ pub fn hadec_to_parallactic_angle(ha: f64, dec: f64, phi: f64) -> f64 {
 /// * `phi`: latitude (geodetic, radians)
 /// * `height`: height above ellipsoid (geodetic, metres, Note 1)
 ///
+/// # Errors
+///
+/// This function will return an error if the parameters of the reference
+/// ellipsoid are invalid (see [`geocentric_to_geodetic_inner`]).  This cannot
+/// happen for the standard [`Ellipsoid`](crate::Ellipsoid)s, nor for a
+/// [`ReferenceEllipsoid`] made with [`ReferenceEllipsoid::new`].
+///
 /// # Notes:
 ///
 /// 1) The geocentric vector (`xyz`, given) and height (`height`, returned) are
 ///    in meters.
 ///
-pub fn geocentric_to_geodetic(e: Ellipsoid, xyz: [f64; 3]) -> [f64; 3] {
-    let (a, f) = e.get_params();
+/// 2) `e` can be one of the standard [`Ellipsoid`](crate::Ellipsoid)s or any
+///    [`ReferenceEllipsoid`].
+///
+pub fn geocentric_to_geodetic<E: Into<ReferenceEllipsoid>>(
+    e: E,
+    xyz: [f64; 3],
+) -> Result<[f64; 3], ErfaError> {
+    let (a, f) = e.into().get_params();
     geocentric_to_geodetic_inner(a, f, xyz)
-        .expect("There are issues with the reference ellipsoid values")
 }
 
 /// Transform geocentric coordinates to geodetic for a reference ellipsoid of
@@ -274,7 +456,14 @@ pub fn geocentric_to_geodetic(e: Ellipsoid, xyz: [f64; 3]) -> [f64; 3] {
 /// # Errors
 ///
 /// This function will return an error if input arguments `a` or `f` are
-/// invalid; valid values are `a` <= 0 and 0 <= `f` < 1.
+/// invalid:
+///
+/// * [`ErfaError::InvalidFlattening`] if `f` is not in the range 0 <= `f` < 1
+///   (ERFA status -1);
+///
+/// * [`ErfaError::InvalidEquatorialRadius`] if `a` <= 0 (ERFA status -2).
+///
+/// The flattening is checked first, as in ERFA.
 ///
 /// # Notes:
 ///
@@ -297,19 +486,24 @@ pub fn geocentric_to_geodetic(e: Ellipsoid, xyz: [f64; 3]) -> [f64; 3] {
 ///   accelerated by Halley's method", J.Geodesy (2006) 79: 689-693
 ///
 pub fn geocentric_to_geodetic_inner(a: f64, f: f64, xyz: [f64; 3]) -> Result<[f64; 3], ErfaError> {
+    check_ellipsoid("geocentric_to_geodetic_inner", a, f)?;
+    Ok(geocentric_to_geodetic_unchecked(a, f, xyz))
+}
+
+/// Validate the ellipsoid parameters given to [`geocentric_to_geodetic_inner`].
+pub(crate) fn check_ellipsoid(function: &'static str, a: f64, f: f64) -> Result<(), ErfaError> {
     if !(0.0..1.0).contains(&f) {
-        return Err(ErfaError::InvalidValue {
-            function: "geocentric_to_geodetic_inner",
-            value: "f",
-        });
+        return Err(ErfaError::InvalidFlattening { function, f });
     }
     if a <= 0.0 {
-        return Err(ErfaError::InvalidValue {
-            function: "geocentric_to_geodetic_inner",
-            value: "a",
-        });
+        return Err(ErfaError::InvalidEquatorialRadius { function, a });
     }
+    Ok(())
+}
 
+/// [`geocentric_to_geodetic_inner`] for ellipsoid parameters that have already
+/// passed [`check_ellipsoid`].
+pub(crate) fn geocentric_to_geodetic_unchecked(a: f64, f: f64, xyz: [f64; 3]) -> [f64; 3] {
     /* Functions of ellipsoid parameters. */
     let aeps2 = a * a * 1e-32;
     let e2 = (2.0 - f) * f;
@@ -318,7 +512,7 @@ pub fn geocentric_to_geodetic_inner(a: f64, f: f64, xyz: [f64; 3]) -> Result<[f6
     // The following line of C code is here for posterity. It is only triggered
     // if `f` >= 1, but we already check that above.
     // if ( ec2 <= 0.0 ) return -1;
-    let ec = ec2.sqrt();
+    let ec = math::sqrt(ec2);
     let b = a * ec;
 
     /* Cartesian components. */
@@ -330,7 +524,7 @@ pub fn geocentric_to_geodetic_inner(a: f64, f: f64, xyz: [f64; 3]) -> Result<[f6
     let p2 = x * x + y * y;
 
     /* Longitude. */
-    let elong = if p2 > 0.0 { y.atan2(x) } else { 0.0 };
+    let elong = if p2 > 0.0 { math::atan2(y, x) } else { 0.0 };
 
     /* Unsigned z-coordinate. */
     let absz = z.abs();
@@ -338,7 +532,7 @@ pub fn geocentric_to_geodetic_inner(a: f64, f: f64, xyz: [f64; 3]) -> Result<[f6
     /* Proceed unless polar case. */
     let (mut phi, height) = if p2 > aeps2 {
         /* Distance from polar axis. */
-        let p = p2.sqrt();
+        let p = math::sqrt(p2);
 
         /* Normalization. */
         let s0 = absz / a;
@@ -352,7 +546,7 @@ pub fn geocentric_to_geodetic_inner(a: f64, f: f64, xyz: [f64; 3]) -> Result<[f6
         let s02 = s0 * s0;
         let s03 = s02 * s0;
         let a02 = c02 + s02;
-        let a0 = a02.sqrt();
+        let a0 = math::sqrt(a02);
         let a03 = a02 * a0;
         let d0 = zc * a03 + e2 * s03;
         let f0 = pn * a03 - e2 * c03;
@@ -363,10 +557,10 @@ pub fn geocentric_to_geodetic_inner(a: f64, f: f64, xyz: [f64; 3]) -> Result<[f6
         let cc = ec * (f0 * f0 - b0 * c0);
 
         /* Evaluate latitude and height. */
-        let phi = (s1 / cc).atan();
+        let phi = math::atan(s1 / cc);
         let s12 = s1 * s1;
         let cc2 = cc * cc;
-        let height = (p * cc + absz * s1 - a * (ec2 * s12 + cc2).sqrt()) / (s12 + cc2).sqrt();
+        let height = (p * cc + absz * s1 - a * math::sqrt(ec2 * s12 + cc2)) / math::sqrt(s12 + cc2);
 
         (phi, height)
     } else {
@@ -379,14 +573,14 @@ pub fn geocentric_to_geodetic_inner(a: f64, f: f64, xyz: [f64; 3]) -> Result<[f6
         phi *= -1.0;
     }
 
-    Ok([elong, phi, height])
+    [elong, phi, height]
 }
 
 /// Transform geodetic coordinates to geocentric using the specified reference
 /// ellipsoid. (`eraGd2gc`)
 ///
 /// Given:
-/// * `e`: ellipsoid identifier
+/// * `e`: ellipsoid identifier (Note 3)
 /// * `elong`: longitude (radians, east +ve)
 /// * `phi`: latitude (geodetic, radians, Note 2)
 /// * `height`: height above ellipsoid (geodetic, metres, Notes 1,2)
@@ -407,13 +601,16 @@ pub fn geocentric_to_geodetic_inner(a: f64, f: f64, xyz: [f64; 3]) -> Result<[f6
 /// 2) No validation is performed on the arguments `elong`, `phi` and `height`.
 ///    An error indicates a case that would lead to arithmetic exceptions.
 ///
-pub fn geodetic_to_geocentric(
-    e: Ellipsoid,
-    elong: f64,
-    phi: f64,
-    height: f64,
-) -> Result<[f64; 3], ErfaError> {
-    let (a, f) = e.get_params();
+/// 3) `e` can be one of the standard [`Ellipsoid`](crate::Ellipsoid)s or any
+///    [`ReferenceEllipsoid`].
+///
+pub fn geodetic_to_geocentric<R: Real, E: Into<ReferenceEllipsoid>>(
+    e: E,
+    elong: R,
+    phi: R,
+    height: R,
+) -> Result<[R; 3], ErfaError> {
+    let (a, f) = e.into().get_params();
     geodetic_to_geocentric_inner(a, f, elong, phi, height)
 }
 
@@ -458,28 +655,41 @@ pub fn geodetic_to_geocentric(
 /// * Explanatory Supplement to the Astronomical Almanac, P. Kenneth Seidelmann
 ///   (ed), University Science Books (1992), Section 4.22, p202.
 ///
-pub fn geodetic_to_geocentric_inner(
+pub fn geodetic_to_geocentric_inner<R: Real>(
     a: f64,
     f: f64,
-    elong: f64,
-    phi: f64,
-    height: f64,
-) -> Result<[f64; 3], ErfaError> {
+    elong: R,
+    phi: R,
+    height: R,
+) -> Result<[R; 3], ErfaError> {
     /* Functions of geodetic latitude. */
     let (sp, cp) = phi.sin_cos();
     let w = 1.0 - f;
     let w = w * w;
-    let d = cp * cp + w * sp * sp;
-    if d <= 0.0 {
-        return Err(ErfaError::Unrealistic {
-            function: "geodetic_to_geocentric_inner",
-        });
+    geodetic_to_geocentric_sc(a, w, elong, sp, cp, height).ok_or(ErfaError::Unrealistic {
+        function: "geodetic_to_geocentric_inner",
+    })
+}
+
+/// [`geodetic_to_geocentric_inner`] given `w` = (1-f)^2 and the sine and cosine
+/// of the latitude. Returns `None` for unrealistic cases.
+pub(crate) fn geodetic_to_geocentric_sc<R: Real>(
+    a: f64,
+    w: f64,
+    elong: R,
+    sp: R,
+    cp: R,
+    height: R,
+) -> Option<[R; 3]> {
+    let d = cp * cp + sp * w * sp;
+    if d <= R::from_f64(0.0) {
+        return None;
     }
-    let ac = a / d.sqrt();
-    let as_ = w * ac;
+    let ac = R::from_f64(a) / d.sqrt();
+    let as_ = ac * w;
 
     /* Geocentric vector. */
     let r = (ac + height) * cp;
     let (s_elong, c_elong) = elong.sin_cos();
-    Ok([r * c_elong, r * s_elong, (as_ + height) * sp])
+    Some([r * c_elong, r * s_elong, (as_ + height) * sp])
 }
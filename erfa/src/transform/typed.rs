@@ -0,0 +1,166 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Variants of the [`transform`](super) functions that take and return typed
+//! angles ([`Angle`], [`Latitude`] and [`HourAngle`]) rather than bare `f64`
+//! radians. See the untyped functions for full documentation; the results are
+//! identical.
+
+use crate::{
+    angle::{Angle, HourAngle, Latitude},
+    ellipsoid::ReferenceEllipsoid,
+    ErfaError,
+};
+
+/// Horizon to equatorial coordinates:  transform azimuth and altitude to hour
+/// angle and declination. (`eraAe2hd`)
+///
+/// Given:
+/// * `az`: azimuth
+/// * `el`: altitude (informally, elevation)
+/// * `phi`: site latitude
+///
+/// Returned:
+/// * `ha`: hour angle (local)
+/// * `dec`: declination
+///
+/// See [`azel_to_hadec`](super::azel_to_hadec).
+///
+pub fn azel_to_hadec(az: Angle, el: Angle, phi: Latitude) -> (HourAngle, Latitude) {
+    let (ha, dec) = super::azel_to_hadec(az.radians(), el.radians(), phi.radians());
+    // `dec` is the result of an atan2 with a non-negative second argument, so
+    // it's always a valid latitude.
+    (
+        HourAngle::from_radians(ha),
+        Latitude::new_unchecked(Angle::from_radians(dec)),
+    )
+}
+
+/// Equatorial to horizon coordinates: transform hour angle and declination to
+/// azimuth and altitude. (`eraHd2ae`)
+///
+/// Given:
+/// * `ha`: hour angle (local)
+/// * `dec`: declination
+/// * `phi`: site latitude
+///
+/// Returned:
+/// * `az`: azimuth
+/// * `el`: altitude (informally, elevation)
+///
+/// See [`hadec_to_azel`](super::hadec_to_azel).
+///
+pub fn hadec_to_azel(ha: HourAngle, dec: Latitude, phi: Latitude) -> (Angle, Angle) {
+    let (az, el) = super::hadec_to_azel(ha.radians(), dec.radians(), phi.radians());
+    (Angle::from_radians(az), Angle::from_radians(el))
+}
+
+/// Parallactic angle for a given hour angle and declination. (`eraHd2pa`)
+///
+/// Given:
+/// * `ha` hour angle
+/// * `dec` declination
+/// * `phi` site latitude
+///
+/// Returned:
+/// * parallactic angle
+///
+/// See [`hadec_to_parallactic_angle`](super::hadec_to_parallactic_angle).
+///
+pub fn hadec_to_parallactic_angle(ha: HourAngle, dec: Latitude, phi: Latitude) -> Angle {
+    Angle::from_radians(super::hadec_to_parallactic_angle(
+        ha.radians(),
+        dec.radians(),
+        phi.radians(),
+    ))
+}
+
+/// Transform geocentric coordinates to geodetic using the specified reference
+/// ellipsoid. (`eraGc2gd`)
+///
+/// Given:
+/// * `e`: ellipsoid identifier
+/// * `xyz`: geocentric vector (meters)
+///
+/// Returned:
+/// * `elong`: longitude (east +ve)
+/// * `phi`: latitude (geodetic)
+/// * `height`: height above ellipsoid (geodetic, metres)
+///
+/// # Errors
+///
+/// See [`geocentric_to_geodetic`](super::geocentric_to_geodetic).
+///
+pub fn geocentric_to_geodetic<E: Into<ReferenceEllipsoid>>(
+    e: E,
+    xyz: [f64; 3],
+) -> Result<(Angle, Latitude, f64), ErfaError> {
+    let [elong, phi, height] = super::geocentric_to_geodetic(e, xyz)?;
+    Ok((
+        Angle::from_radians(elong),
+        Latitude::new_unchecked(Angle::from_radians(phi)),
+        height,
+    ))
+}
+
+/// Transform geodetic coordinates to geocentric using the specified reference
+/// ellipsoid. (`eraGd2gc`)
+///
+/// Given:
+/// * `e`: ellipsoid identifier
+/// * `elong`: longitude (east +ve)
+/// * `phi`: latitude (geodetic)
+/// * `height`: height above ellipsoid (geodetic, metres)
+///
+/// Returned:
+/// * `xyz`: geocentric vector (meters)
+///
+/// # Errors
+///
+/// See [`geodetic_to_geocentric`](super::geodetic_to_geocentric).
+///
+pub fn geodetic_to_geocentric<E: Into<ReferenceEllipsoid>>(
+    e: E,
+    elong: Angle,
+    phi: Latitude,
+    height: f64,
+) -> Result<[f64; 3], ErfaError> {
+    super::geodetic_to_geocentric(e, elong.radians(), phi.radians(), height)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::Ellipsoid;
+
+    #[test]
+    fn test_typed_matches_untyped() {
+        let ha = HourAngle::from_hours(1.5);
+        let dec = Latitude::from_degrees(-26.7).unwrap();
+        let phi = Latitude::from_degrees(-26.70331940).unwrap();
+
+        let (az, el) = hadec_to_azel(ha, dec, phi);
+        let expected = super::super::hadec_to_azel(ha.radians(), dec.radians(), phi.radians());
+        assert_abs_diff_eq!(az.radians(), expected.0);
+        assert_abs_diff_eq!(el.radians(), expected.1);
+
+        let (ha2, dec2) = azel_to_hadec(az, el, phi);
+        assert_abs_diff_eq!(ha2.radians(), ha.radians(), epsilon = 1e-12);
+        assert_abs_diff_eq!(dec2.radians(), dec.radians(), epsilon = 1e-12);
+
+        let pa = hadec_to_parallactic_angle(ha, dec, phi);
+        let expected =
+            super::super::hadec_to_parallactic_angle(ha.radians(), dec.radians(), phi.radians());
+        assert_abs_diff_eq!(pa.radians(), expected);
+
+        let elong = Angle::from_degrees(116.67081524);
+        let xyz = geodetic_to_geocentric(Ellipsoid::WGS84, elong, phi, 377.827).unwrap();
+        let (elong2, phi2, height) = geocentric_to_geodetic(Ellipsoid::WGS84, xyz).unwrap();
+        assert_abs_diff_eq!(elong2.radians(), elong.radians(), epsilon = 1e-12);
+        assert_abs_diff_eq!(phi2.radians(), phi.radians(), epsilon = 1e-12);
+        assert_abs_diff_eq!(height, 377.827, epsilon = 1e-6);
+    }
+}
@@ -4,6 +4,11 @@
 
 //! Code for vectors ("p-vectors" and "pv-vectors") and "r-matrices".
 
+mod types;
+pub use types::{PVector, PvVector, RMatrix};
+
+use crate::{math, real::Real};
+
 /// Multiply a p-vector by a scalar. (`eraSxp`)
 ///
 /// Given:
@@ -26,7 +31,7 @@ pub fn multiply(s: f64, p: [f64; 3]) -> [f64; 3] {
 /// * modulus
 ///
 pub fn modulus(p: [f64; 3]) -> f64 {
-    (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt()
+    math::sqrt(p[0] * p[0] + p[1] * p[1] + p[2] * p[2])
 }
 
 /// Convert a p-vector into modulus and unit vector. (`eraPn`)
@@ -125,6 +130,47 @@ pub fn mat_mul_pvvec(r: [[f64; 3]; 3], pv: [[f64; 3]; 2]) -> [[f64; 3]; 2] {
     [rp1, rp2]
 }
 
+/// Multiply a p-vector by the transpose of an r-matrix. (`eraTrxp`)
+///
+/// Given:
+/// * `r`: r-matrix
+/// * `p`: p-vector
+///
+/// Returned:
+/// * `trp`: `r^T * p`
+///
+pub fn transpose_mul_pvec(r: [[f64; 3]; 3], p: [f64; 3]) -> [f64; 3] {
+    /* Transpose of matrix r. */
+    let tr = transpose(r);
+
+    /* Matrix tr * vector p -> vector trp. */
+    mat_mul_pvec(tr, p)
+}
+
+/// Multiply a pv-vector by the transpose of an r-matrix. (`eraTrxpv`)
+///
+/// Given:
+/// * `r`: r-matrix
+/// * `pv`: pv-vector
+///
+/// Returned:
+/// * `trpv`: `r^T * pv`
+///
+/// # Note:
+///
+/// 1) The algorithm is for the simple case where the r-matrix `r` is not a
+///    function of time.  The case where `r` is a function of time leads to an
+///    additional velocity component equal to the product of the derivative of
+///    the transpose of `r` and the position vector.
+///
+pub fn transpose_mul_pvvec(r: [[f64; 3]; 3], pv: [[f64; 3]; 2]) -> [[f64; 3]; 2] {
+    /* Transpose of matrix r. */
+    let tr = transpose(r);
+
+    /* Matrix tr * vector pv -> vector trpv. */
+    mat_mul_pvvec(tr, pv)
+}
+
 /// Multiply two r-matrices. (`eraRxr`)
 ///
 /// Given:
@@ -150,21 +196,59 @@ pub fn multiply_matrices(a: [[f64; 3]; 3], b: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
     wm
 }
 
+/// Transpose an r-matrix. (`eraTr`)
+///
+/// Given:
+/// * `r`: r-matrix
+///
+/// Returned:
+/// * `rt`: transpose
+///
+pub fn transpose(r: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut wm = [[0.0; 3]; 3];
+
+    for (i, r) in r.iter().enumerate() {
+        for (j, r) in r.iter().enumerate() {
+            wm[j][i] = *r;
+        }
+    }
+
+    wm
+}
+
 /// Initialize an r-matrix to the identity matrix. (`eraIr`)
 ///
 /// Modified:
 ///  * `r`: r-matrix
 ///
-pub fn init_matrix(r: &mut [[f64; 3]; 3]) {
-    r[0][0] = 1.0;
+pub fn init_matrix<R: Real>(r: &mut [[R; 3]; 3]) {
+    let (zero, one) = (R::from_f64(0.0), R::from_f64(1.0));
+    r[0][0] = one;
+    r[0][1] = zero;
+    r[0][2] = zero;
+    r[1][0] = zero;
+    r[1][1] = one;
+    r[1][2] = zero;
+    r[2][0] = zero;
+    r[2][1] = zero;
+    r[2][2] = one;
+}
+
+/// Initialize an r-matrix to the null matrix. (`eraZr`)
+///
+/// Modified:
+///  * `r`: r-matrix
+///
+pub fn zero_matrix(r: &mut [[f64; 3]; 3]) {
+    r[0][0] = 0.0;
     r[0][1] = 0.0;
     r[0][2] = 0.0;
     r[1][0] = 0.0;
-    r[1][1] = 1.0;
+    r[1][1] = 0.0;
     r[1][2] = 0.0;
     r[2][0] = 0.0;
     r[2][1] = 0.0;
-    r[2][2] = 1.0;
+    r[2][2] = 0.0;
 }
 
 /// Rotate an r-matrix about the x-axis. (`eraRx`)
@@ -188,7 +272,7 @@ pub fn init_matrix(r: &mut [[f64; 3]; 3]) {
 ///    | 0 | +cos(phi) | +sin(phi) |
 ///    | 0 | -sin(phi) | +cos(phi) |
 ///
-pub fn rotate_x(phi: f64, r: &mut [[f64; 3]; 3]) {
+pub fn rotate_x<R: Real>(phi: R, r: &mut [[R; 3]; 3]) {
     let (s, c) = phi.sin_cos();
 
     let a10 = c * r[1][0] + s * r[2][0];
@@ -206,6 +290,46 @@ pub fn rotate_x(phi: f64, r: &mut [[f64; 3]; 3]) {
     r[2][2] = a22;
 }
 
+/// Rotate an r-matrix about the y-axis. (`eraRy`)
+///
+/// Given:
+///  * `theta`: angle (radians)
+///
+/// Modified:
+///  * `r`: r-matrix, rotated
+///
+/// # Notes:
+///
+/// 1) Calling this function with positive `theta` incorporates in the supplied
+///    r-matrix `r` an additional rotation, about the y-axis, anticlockwise as
+///    seen looking towards the origin from positive y.
+///
+/// 2) The additional rotation can be represented by this matrix:
+///
+///    | +cos(theta) | 0 | -sin(theta) |
+///    |             |   |             |
+///    |      0      | 1 |      0      |
+///    |             |   |             |
+///    | +sin(theta) | 0 | +cos(theta) |
+///
+pub fn rotate_y<R: Real>(theta: R, r: &mut [[R; 3]; 3]) {
+    let (s, c) = theta.sin_cos();
+
+    let a00 = c * r[0][0] - s * r[2][0];
+    let a01 = c * r[0][1] - s * r[2][1];
+    let a02 = c * r[0][2] - s * r[2][2];
+    let a20 = s * r[0][0] + c * r[2][0];
+    let a21 = s * r[0][1] + c * r[2][1];
+    let a22 = s * r[0][2] + c * r[2][2];
+
+    r[0][0] = a00;
+    r[0][1] = a01;
+    r[0][2] = a02;
+    r[2][0] = a20;
+    r[2][1] = a21;
+    r[2][2] = a22;
+}
+
 /// Rotate an r-matrix about the z-axis. (`eraRz`)
 ///
 /// Given:
@@ -228,7 +352,7 @@ pub fn rotate_x(phi: f64, r: &mut [[f64; 3]; 3]) {
 ///    |           |           |   |
 ///    |     0     |     0     | 1 |
 ///
-pub fn rotate_z(psi: f64, r: &mut [[f64; 3]; 3]) {
+pub fn rotate_z<R: Real>(psi: R, r: &mut [[R; 3]; 3]) {
     let (s, c) = psi.sin_cos();
 
     let a00 = c * r[0][0] + s * r[1][0];
@@ -246,6 +370,88 @@ pub fn rotate_z(psi: f64, r: &mut [[f64; 3]; 3]) {
     r[1][2] = a12;
 }
 
+/// Express an r-matrix as an r-vector. (`eraRm2v`)
+///
+/// Given:
+/// * `r`: rotation matrix
+///
+/// Returned:
+/// * `w`: rotation vector (Note 1)
+///
+/// # Notes:
+///
+/// 1) A rotation matrix describes a rotation through some angle about some
+///    arbitrary axis called the Euler axis.  The "rotation vector" returned by
+///    this function has the same direction as the Euler axis, and its magnitude
+///    is the angle in radians.  (The magnitude and direction can be separated
+///    by means of the function [`eraPn`](modulus_and_unit_vector).)
+///
+/// 2) If `r` is null, so is the result.  If `r` is not a rotation matrix the
+///    result is undefined; `r` must be proper (i.e. have a positive
+///    determinant) and real orthogonal (inverse = transpose).
+///
+/// 3) The reference frame rotates clockwise as seen looking along the rotation
+///    vector from the origin.
+///
+pub fn matrix_to_rotation_vector(r: [[f64; 3]; 3]) -> [f64; 3] {
+    let x = r[1][2] - r[2][1];
+    let y = r[2][0] - r[0][2];
+    let z = r[0][1] - r[1][0];
+    let s2 = math::sqrt(x * x + y * y + z * z);
+    if s2 > 0.0 {
+        let c2 = r[0][0] + r[1][1] + r[2][2] - 1.0;
+        let phi = math::atan2(s2, c2);
+        let f = phi / s2;
+        [x * f, y * f, z * f]
+    } else {
+        [0.0; 3]
+    }
+}
+
+/// Form the r-matrix corresponding to a given r-vector. (`eraRv2m`)
+///
+/// Given:
+/// * `w`: rotation vector (Note 1)
+///
+/// Returned:
+/// * `r`: rotation matrix
+///
+/// # Notes:
+///
+/// 1) A rotation matrix describes a rotation through some angle about some
+///    arbitrary axis called the Euler axis.  The "rotation vector" supplied to
+///    this function has the same direction as the Euler axis, and its magnitude
+///    is the angle in radians.
+///
+/// 2) If `w` is null, the identity matrix is returned.
+///
+/// 3) The reference frame rotates clockwise as seen looking along the rotation
+///    vector from the origin.
+///
+pub fn rotation_vector_to_matrix(w: [f64; 3]) -> [[f64; 3]; 3] {
+    /* Euler angle (magnitude of rotation vector) and functions. */
+    let mut x = w[0];
+    let mut y = w[1];
+    let mut z = w[2];
+    let phi = math::sqrt(x * x + y * y + z * z);
+    let (s, c) = math::sin_cos(phi);
+    let f = 1.0 - c;
+
+    /* Euler axis (direction of rotation vector), perhaps null. */
+    if phi > 0.0 {
+        x /= phi;
+        y /= phi;
+        z /= phi;
+    }
+
+    /* Form the rotation matrix. */
+    [
+        [x * x * f + c, x * y * f + z * s, x * z * f - y * s],
+        [y * x * f - z * s, y * y * f + c, y * z * f + x * s],
+        [z * x * f + y * s, z * y * f - x * s, z * z * f + c],
+    ]
+}
+
 /// Copy a p-vector. (`eraCp`)
 ///
 /// Given:
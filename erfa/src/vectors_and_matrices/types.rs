@@ -0,0 +1,468 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Newtypes around p-vectors, pv-vectors and r-matrices with operator
+//! overloading. These are thin wrappers; the arithmetic is done by the free
+//! functions in [`vectors_and_matrices`](super), so results are identical.
+
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+/// A p-vector: a position or other Cartesian 3-vector.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PVector(pub [f64; 3]);
+
+/// A pv-vector: a position and velocity pair.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PvVector {
+    pub position: PVector,
+    pub velocity: PVector,
+}
+
+/// An r-matrix: a 3x3 rotation matrix, indexed by row then column.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RMatrix(pub [[f64; 3]; 3]);
+
+impl PVector {
+    /// The null vector.
+    pub const ZERO: Self = Self([0.0; 3]);
+
+    /// Create a p-vector from its components.
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self([x, y, z])
+    }
+
+    /// Modulus of the p-vector. (`eraPm`)
+    pub fn modulus(self) -> f64 {
+        super::modulus(self.0)
+    }
+
+    /// The modulus and unit vector of the p-vector. A null vector gives a
+    /// modulus of zero and a null unit vector. (`eraPn`)
+    pub fn modulus_and_unit_vector(self) -> (f64, Self) {
+        let (r, u) = super::modulus_and_unit_vector(self.0);
+        (r, Self(u))
+    }
+
+    /// Inner (=scalar=dot) product. (`eraPdp`)
+    pub fn dot(self, other: Self) -> f64 {
+        super::inner_product(self.0, other.0)
+    }
+
+    /// Outer (=vector=cross) product. See
+    /// [`outer_product`](super::outer_product).
+    pub fn cross(self, other: Self) -> Self {
+        Self(super::outer_product(self.0, other.0))
+    }
+}
+
+impl PvVector {
+    /// The null pv-vector.
+    pub const ZERO: Self = Self {
+        position: PVector::ZERO,
+        velocity: PVector::ZERO,
+    };
+
+    /// Create a pv-vector from a position and a velocity.
+    pub const fn new(position: PVector, velocity: PVector) -> Self {
+        Self { position, velocity }
+    }
+}
+
+impl RMatrix {
+    /// The identity matrix. (`eraIr`)
+    pub const IDENTITY: Self = Self([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+
+    /// The null matrix. (`eraZr`)
+    pub const ZERO: Self = Self([[0.0; 3]; 3]);
+
+    /// The transpose of the matrix. For a rotation matrix, this is the
+    /// inverse. (`eraTr`)
+    pub fn transpose(self) -> Self {
+        Self(super::transpose(self.0))
+    }
+
+    /// Multiply a p-vector by the transpose of this matrix. (`eraTrxp`)
+    pub fn transpose_mul_pvec(self, p: PVector) -> PVector {
+        PVector(super::transpose_mul_pvec(self.0, p.0))
+    }
+
+    /// Multiply a pv-vector by the transpose of this matrix. (`eraTrxpv`)
+    pub fn transpose_mul_pvvec(self, pv: PvVector) -> PvVector {
+        super::transpose_mul_pvvec(self.0, pv.into()).into()
+    }
+
+    /// Rotate the matrix about the x-axis. (`eraRx`)
+    pub fn rotate_x(&mut self, phi: f64) {
+        super::rotate_x(phi, &mut self.0)
+    }
+
+    /// Rotate the matrix about the y-axis. (`eraRy`)
+    pub fn rotate_y(&mut self, theta: f64) {
+        super::rotate_y(theta, &mut self.0)
+    }
+
+    /// Rotate the matrix about the z-axis. (`eraRz`)
+    pub fn rotate_z(&mut self, psi: f64) {
+        super::rotate_z(psi, &mut self.0)
+    }
+
+    /// The matrix corresponding to the rotation vector `w`. (`eraRv2m`)
+    pub fn from_rotation_vector(w: PVector) -> Self {
+        Self(super::rotation_vector_to_matrix(w.0))
+    }
+
+    /// Express the matrix as a rotation vector. (`eraRm2v`)
+    pub fn to_rotation_vector(self) -> PVector {
+        PVector(super::matrix_to_rotation_vector(self.0))
+    }
+}
+
+impl Default for RMatrix {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/* Conversions to and from the raw arrays. */
+
+impl From<[f64; 3]> for PVector {
+    fn from(p: [f64; 3]) -> Self {
+        Self(p)
+    }
+}
+
+impl From<PVector> for [f64; 3] {
+    fn from(p: PVector) -> Self {
+        p.0
+    }
+}
+
+impl From<[[f64; 3]; 2]> for PvVector {
+    fn from(pv: [[f64; 3]; 2]) -> Self {
+        Self::new(PVector(pv[0]), PVector(pv[1]))
+    }
+}
+
+impl From<PvVector> for [[f64; 3]; 2] {
+    fn from(pv: PvVector) -> Self {
+        [pv.position.0, pv.velocity.0]
+    }
+}
+
+impl From<[[f64; 3]; 3]> for RMatrix {
+    fn from(r: [[f64; 3]; 3]) -> Self {
+        Self(r)
+    }
+}
+
+impl From<RMatrix> for [[f64; 3]; 3] {
+    fn from(r: RMatrix) -> Self {
+        r.0
+    }
+}
+
+/* Indexing. */
+
+impl Index<usize> for PVector {
+    type Output = f64;
+
+    fn index(&self, i: usize) -> &f64 {
+        &self.0[i]
+    }
+}
+
+impl IndexMut<usize> for PVector {
+    fn index_mut(&mut self, i: usize) -> &mut f64 {
+        &mut self.0[i]
+    }
+}
+
+/// Index 0 is the position and index 1 the velocity, as in ERFA.
+impl Index<usize> for PvVector {
+    type Output = PVector;
+
+    fn index(&self, i: usize) -> &PVector {
+        match i {
+            0 => &self.position,
+            1 => &self.velocity,
+            _ => panic!("pv-vector index out of bounds: {i}"),
+        }
+    }
+}
+
+impl IndexMut<usize> for PvVector {
+    fn index_mut(&mut self, i: usize) -> &mut PVector {
+        match i {
+            0 => &mut self.position,
+            1 => &mut self.velocity,
+            _ => panic!("pv-vector index out of bounds: {i}"),
+        }
+    }
+}
+
+/// Indexes a row.
+impl Index<usize> for RMatrix {
+    type Output = [f64; 3];
+
+    fn index(&self, i: usize) -> &[f64; 3] {
+        &self.0[i]
+    }
+}
+
+impl IndexMut<usize> for RMatrix {
+    fn index_mut(&mut self, i: usize) -> &mut [f64; 3] {
+        &mut self.0[i]
+    }
+}
+
+/// Indexes an element by (row, column).
+impl Index<(usize, usize)> for RMatrix {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        &self.0[i][j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for RMatrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        &mut self.0[i][j]
+    }
+}
+
+/* Arithmetic. */
+
+/// p-vector addition. (`eraPpp`)
+impl Add for PVector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self([self[0] + rhs[0], self[1] + rhs[1], self[2] + rhs[2]])
+    }
+}
+
+/// p-vector subtraction. (`eraPmp`)
+impl Sub for PVector {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self([self[0] - rhs[0], self[1] - rhs[1], self[2] - rhs[2]])
+    }
+}
+
+impl Neg for PVector {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self([-self[0], -self[1], -self[2]])
+    }
+}
+
+/// Multiply a p-vector by a scalar. (`eraSxp`)
+impl Mul<f64> for PVector {
+    type Output = Self;
+
+    fn mul(self, s: f64) -> Self {
+        Self(super::multiply(s, self.0))
+    }
+}
+
+/// Multiply a p-vector by a scalar. (`eraSxp`)
+impl Mul<PVector> for f64 {
+    type Output = PVector;
+
+    fn mul(self, p: PVector) -> PVector {
+        p * self
+    }
+}
+
+impl AddAssign for PVector {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for PVector {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// pv-vector addition. (`eraPvppv`)
+impl Add for PvVector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.position + rhs.position, self.velocity + rhs.velocity)
+    }
+}
+
+/// pv-vector subtraction. (`eraPvmpv`)
+impl Sub for PvVector {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.position - rhs.position, self.velocity - rhs.velocity)
+    }
+}
+
+impl Neg for PvVector {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.position, -self.velocity)
+    }
+}
+
+/// Multiply a pv-vector by a scalar. (`eraSxpv`)
+impl Mul<f64> for PvVector {
+    type Output = Self;
+
+    fn mul(self, s: f64) -> Self {
+        Self::new(self.position * s, self.velocity * s)
+    }
+}
+
+/// Multiply a pv-vector by a scalar. (`eraSxpv`)
+impl Mul<PvVector> for f64 {
+    type Output = PvVector;
+
+    fn mul(self, pv: PvVector) -> PvVector {
+        pv * self
+    }
+}
+
+impl AddAssign for PvVector {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for PvVector {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Multiply two r-matrices. (`eraRxr`)
+impl Mul for RMatrix {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(super::multiply_matrices(self.0, rhs.0))
+    }
+}
+
+/// Multiply a p-vector by an r-matrix. (`eraRxp`)
+impl Mul<PVector> for RMatrix {
+    type Output = PVector;
+
+    fn mul(self, p: PVector) -> PVector {
+        PVector(super::mat_mul_pvec(self.0, p.0))
+    }
+}
+
+/// Multiply a pv-vector by an r-matrix. (`eraRxpv`)
+impl Mul<PvVector> for RMatrix {
+    type Output = PvVector;
+
+    fn mul(self, pv: PvVector) -> PvVector {
+        super::mat_mul_pvvec(self.0, pv.into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::vectors_and_matrices::*;
+
+    #[test]
+    fn test_matches_free_functions() {
+        let a = [[2.0, 3.0, 2.0], [3.0, 2.0, 3.0], [3.0, 4.0, 5.0]];
+        let b = [[1.0, 2.0, 2.0], [4.0, 1.0, 1.0], [3.0, 0.0, 1.0]];
+        let p = [0.2, 1.5, 0.1];
+        let pv = [[0.2, 1.5, 0.1], [1.5, 0.2, 0.1]];
+
+        let (ra, rb) = (RMatrix::from(a), RMatrix::from(b));
+        assert_eq!(<[[f64; 3]; 3]>::from(ra * rb), multiply_matrices(a, b));
+        assert_eq!(<[f64; 3]>::from(ra * PVector(p)), mat_mul_pvec(a, p));
+        assert_eq!(
+            <[[f64; 3]; 2]>::from(ra * PvVector::from(pv)),
+            mat_mul_pvvec(a, pv)
+        );
+        assert_eq!(ra.transpose().0, transpose(a));
+        assert_eq!(
+            ra.transpose_mul_pvec(PVector(p)).0,
+            transpose_mul_pvec(a, p)
+        );
+        assert_eq!(
+            <[[f64; 3]; 2]>::from(ra.transpose_mul_pvvec(pv.into())),
+            transpose_mul_pvvec(a, pv)
+        );
+        assert_eq!(
+            mat_mul_pvec(multiply_matrices(a, b), p),
+            (ra * rb * PVector(p)).0
+        );
+
+        let q = PVector::new(2.0, 1.5, -1.0);
+        assert_eq!(PVector(p).dot(q), inner_product(p, q.0));
+        assert_eq!(PVector(p).cross(q).0, outer_product(p, q.0));
+        assert_eq!(PVector(p).modulus(), modulus(p));
+        assert_eq!((2.0 * q).0, multiply(2.0, q.0));
+    }
+
+    #[test]
+    fn test_arithmetic_and_indexing() {
+        let p = PVector::new(1.0, 2.0, 3.0);
+        let q = PVector::new(0.5, -1.0, 4.0);
+        assert_eq!(p + q, PVector::new(1.5, 1.0, 7.0));
+        assert_eq!(p - q, PVector::new(0.5, 3.0, -1.0));
+        assert_eq!(-p, PVector::new(-1.0, -2.0, -3.0));
+        let mut r = p;
+        r += q;
+        r -= q;
+        assert_eq!(r, p);
+        assert_eq!(p[2], 3.0);
+
+        let pv = PvVector::new(p, q);
+        assert_eq!(pv[0], p);
+        assert_eq!(pv[1], q);
+        assert_eq!(pv + pv, pv * 2.0);
+        assert_eq!(pv - pv, PvVector::ZERO);
+        assert_eq!(-pv, PvVector::new(-p, -q));
+
+        let mut m = RMatrix::IDENTITY;
+        assert_eq!(m * p, p);
+        m[(0, 1)] = 2.0;
+        assert_eq!(m[0], [1.0, 2.0, 0.0]);
+        assert_eq!(m.transpose()[1][0], 2.0);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let mut m = RMatrix::IDENTITY;
+        m.rotate_z(0.3);
+        m.rotate_y(-0.2);
+        m.rotate_x(0.1);
+
+        // A rotation matrix's transpose is its inverse.
+        let i = m * m.transpose();
+        for row in 0..3 {
+            for col in 0..3 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert_abs_diff_eq!(i[(row, col)], expected, epsilon = 1e-15);
+            }
+        }
+
+        let w = m.to_rotation_vector();
+        let m2 = RMatrix::from_rotation_vector(w);
+        for row in 0..3 {
+            for col in 0..3 {
+                assert_abs_diff_eq!(m[(row, col)], m2[(row, col)], epsilon = 1e-15);
+            }
+        }
+    }
+}